@@ -0,0 +1,24 @@
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use rand::random_range;
+
+// run this with and without the lookup_table feature to compare them.
+fn cure_char_non_ascii(c: &mut Criterion) {
+  c.bench_function("cure_char_non_ascii", |b| {
+    b.iter_batched(
+      || char::from_u32(random_range(0x80..0x20000)).unwrap_or('\u{fffd}'),
+      |character| decancer::cure_char!(character),
+      BatchSize::SmallInput,
+    );
+  });
+}
+
+fn cure_non_ascii(c: &mut Criterion) {
+  let input = "vＥⓡ𝔂 𝔽𝕌Ňℕｙ ţ乇𝕏𝓣 ｈ̸̢ė̷l͓͊l̸о ꙮк ℍ𝕖𝕝𝕝𝕠 𝓦𝓸𝓻𝓵𝓭 ".repeat(16);
+
+  c.bench_function("cure_non_ascii", |b| {
+    b.iter(|| decancer::cure!(&input).unwrap());
+  });
+}
+
+criterion_group!(benches, cure_char_non_ascii, cure_non_ascii);
+criterion_main!(benches);
@@ -1,4 +1,160 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use std::{collections::HashMap, env, fs, path::Path};
+
+const PAGE_SIZE: u32 = 0x100;
+const PAGE_COUNT: u32 = 0x11_0000 / PAGE_SIZE;
+
+struct Table<'a> {
+  bytes: &'a [u8],
+  offset: usize,
+  count: usize,
+}
+
+impl Table<'_> {
+  fn record(&self, idx: usize) -> (u32, u32) {
+    let offset = self.offset + (idx * 6);
+    let first = u32::from_le_bytes(self.bytes[offset..offset + 4].try_into().unwrap());
+    let codepoint = first & 0x000f_ffff;
+
+    // string translations don't have a range.
+    let range_size = if first >= 0x1000_0000 {
+      0
+    } else {
+      u32::from(self.bytes[offset + 4] & 0x7f)
+    };
+
+    (codepoint, codepoint + range_size)
+  }
+
+  // the same binary search as Codepoint::binary_search, so that the output is identical.
+  #[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+  )]
+  fn search(&self, code: u32) -> Option<usize> {
+    let mut start = 0i32;
+    let mut end = self.count as i32 - 1;
+
+    while start <= end {
+      let mid = start.midpoint(end);
+      let (first, last) = self.record(mid as _);
+
+      if code < first {
+        end = mid - 1;
+      } else if code > last {
+        start = mid + 1;
+      } else {
+        return Some(mid as _);
+      }
+    }
+
+    None
+  }
+
+  fn pages(&self) -> Vec<[u16; PAGE_SIZE as usize]> {
+    let mut pages = vec![[0; PAGE_SIZE as usize]; PAGE_COUNT as usize];
+
+    for idx in 0..self.count {
+      let (first, last) = self.record(idx);
+
+      for code in first..=last {
+        if let Some(found) = self.search(code) {
+          pages[(code / PAGE_SIZE) as usize][(code % PAGE_SIZE) as usize] =
+            u16::try_from(found + 1).unwrap();
+        }
+      }
+    }
+
+    pages
+  }
+}
+
+// layout: the page indices of the main table, the page indices of the case-sensitive table, then every distinct page.
+// every page contains the index of each codepoint's record plus one, or zero if it's not in the table.
+fn generate_lookup_table(codepoints: &[u8]) -> Vec<u8> {
+  let u16_at = |offset: usize| {
+    usize::from(u16::from_le_bytes([
+      codepoints[offset],
+      codepoints[offset + 1],
+    ]))
+  };
+
+  let case_sensitive_offset = u16_at(0);
+  let similar_start = u16_at(2);
+
+  let tables = [
+    Table {
+      bytes: codepoints,
+      offset: 6,
+      count: (case_sensitive_offset - 6) / 6,
+    },
+    Table {
+      bytes: codepoints,
+      offset: case_sensitive_offset,
+      count: (similar_start - case_sensitive_offset) / 6,
+    },
+  ];
+
+  let mut distinct = vec![[0; PAGE_SIZE as usize]];
+  let mut indices = HashMap::from([([0; PAGE_SIZE as usize], 0u16)]);
+  let mut output = vec![];
+
+  for table in tables {
+    for page in table.pages() {
+      let idx = *indices.entry(page).or_insert_with(|| {
+        distinct.push(page);
+        u16::try_from(distinct.len() - 1).unwrap()
+      });
+
+      output.extend_from_slice(&idx.to_le_bytes());
+    }
+  }
+
+  for page in distinct {
+    for value in page {
+      output.extend_from_slice(&value.to_le_bytes());
+    }
+  }
+
+  output
+}
+
+// layout: every character whose lowercase form doesn't uppercase back to it (e.g: the kelvin sign or titlecase digraphs), as a little-endian u32.
+// these are derived from the same unicode data as the standard library's case conversions, which is what cure_char uses.
+fn generate_case_exceptions() -> Vec<u8> {
+  let mut output = vec![];
+
+  for character in '\0'..=char::MAX {
+    let lowercase = character.to_lowercase().next().unwrap();
+    let mut uppercase = lowercase.to_uppercase();
+
+    if lowercase != character && (uppercase.next() != Some(character) || uppercase.next().is_some())
+    {
+      output.extend_from_slice(&u32::from(character).to_le_bytes());
+    }
+  }
+
+  output
+}
+
 fn main() {
-  println!("cargo:rerun-if-changed=bin/bidi.bin");
   println!("cargo:rerun-if-changed=bin/codepoints.bin");
+
+  let out_dir = env::var_os("OUT_DIR").unwrap();
+
+  fs::write(
+    Path::new(&out_dir).join("case_exceptions.bin"),
+    generate_case_exceptions(),
+  )
+  .unwrap();
+
+  if env::var_os("CARGO_FEATURE_LOOKUP_TABLE").is_some() {
+    let codepoints = fs::read("bin/codepoints.bin").unwrap();
+    let output = Path::new(&out_dir).join("lookup.bin");
+
+    fs::write(output, generate_lookup_table(&codepoints)).unwrap();
+  }
 }
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{
+  super::{Error, Scratch, first_cure_pass, resolve_levels},
+  Class, Level, Paragraph,
+};
+use std::{mem, ops::Range};
+
+/// A string that has been resolved by unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text), without curing it.
+///
+/// This exposes the paragraphs, the embedding level of every byte and the visual runs of the string, which is the same implementation that [`cure`][super::super::cure()] uses for reordering. Every paragraph is treated as a single line.
+///
+/// ```rust
+/// use decancer::BidiInfo;
+///
+/// let info = BidiInfo::new("abc אבג\n123").unwrap();
+/// let paragraphs = info.paragraphs();
+///
+/// assert_eq!(paragraphs.len(), 2);
+/// assert_eq!(paragraphs[0].range(), 0..11);
+/// assert!(paragraphs[0].level().is_ltr());
+///
+/// assert_eq!(info.levels()[0].number(), 0);
+/// assert_eq!(info.levels()[4].number(), 1);
+///
+/// let runs: Vec<_> = info
+///   .visual_runs(&paragraphs[0])
+///   .unwrap()
+///   .into_iter()
+///   .map(|(range, _)| range)
+///   .collect();
+///
+/// assert_eq!(runs, [0..4, 4..10, 10..11]);
+/// assert_eq!(info.reordered().unwrap(), "abc גבא\n123");
+/// ```
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "bidi")))]
+pub struct BidiInfo<'a> {
+  text: &'a str,
+  classes: Vec<Class>,
+  levels: Vec<Level>,
+  paragraphs: Vec<Paragraph>,
+}
+
+impl<'a> BidiInfo<'a> {
+  /// Resolves the paragraphs and embedding levels of a string.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it.
+  pub fn new(text: &'a str) -> Result<Self, Error> {
+    let mut scratch = Scratch::default();
+
+    first_cure_pass(text, None, None, &mut scratch);
+    resolve_levels(&mut scratch)?;
+
+    Ok(Self {
+      text,
+      classes: mem::take(&mut scratch.original_classes),
+      levels: mem::take(&mut scratch.levels),
+      paragraphs: mem::take(&mut scratch.paragraphs),
+    })
+  }
+
+  /// Returns the string that has been resolved.
+  #[must_use]
+  pub const fn text(&self) -> &'a str {
+    self.text
+  }
+
+  /// Returns the paragraphs of the string, in logical order.
+  #[must_use]
+  pub fn paragraphs(&self) -> &[Paragraph] {
+    &self.paragraphs
+  }
+
+  /// Returns the resolved embedding level of every byte in the string. Every byte of a multi-byte character has the same embedding level.
+  #[must_use]
+  pub fn levels(&self) -> &[Level] {
+    &self.levels
+  }
+
+  /// Returns the runs of the specified paragraph in visual order (from left to right), along with their embedding level. Each run is a byte range within the string, and the characters of a right-to-left run must be displayed in reverse.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the paragraph is not from this [`BidiInfo`].
+  pub fn visual_runs(&self, paragraph: &Paragraph) -> Result<Vec<(Range<usize>, Level)>, Error> {
+    let mut levels = vec![];
+    let mut runs = vec![];
+
+    paragraph.visual_runs(
+      self.text,
+      &self.classes,
+      &self.levels,
+      &mut levels,
+      &mut runs,
+    )?;
+
+    Ok(
+      runs
+        .into_iter()
+        .map(|run| {
+          let level = levels[run.start];
+
+          (run, level)
+        })
+        .collect(),
+    )
+  }
+
+  /// Returns the string in visual order. Unlike [`cure`][super::super::cure()], characters are not translated or removed.
+  ///
+  /// **NOTE:** Mirrored characters (e.g: brackets in right-to-left text) are not replaced with their mirrored counterparts.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it.
+  pub fn reordered(&self) -> Result<String, Error> {
+    let mut output = String::with_capacity(self.text.len());
+
+    for paragraph in &self.paragraphs {
+      for (run, level) in self.visual_runs(paragraph)? {
+        let text = &self.text[run];
+
+        if level.is_rtl() {
+          output.extend(text.chars().rev());
+        } else {
+          output.push_str(text);
+        }
+      }
+    }
+
+    Ok(output)
+  }
+}
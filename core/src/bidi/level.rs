@@ -3,7 +3,10 @@
 
 use super::{super::Error, Class};
 
+/// A unicode bidi embedding level. Even levels are left-to-right, while odd levels are right-to-left.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "bidi", derive(Debug, Hash))]
+#[cfg_attr(docsrs, doc(cfg(feature = "bidi")))]
 pub struct Level(pub(in super::super) u8);
 
 pub const MAX_EXPLICIT_DEPTH: u8 = 125;
@@ -53,7 +56,23 @@ impl Level {
     Err(Error::LevelModificationOverflow)
   }
 
-  pub(in super::super) const fn is_rtl(self) -> bool {
+  /// Returns the number of this embedding level.
+  #[cfg(feature = "bidi")]
+  #[must_use]
+  pub const fn number(self) -> u8 {
+    self.0
+  }
+
+  /// Returns `true` if this embedding level is left-to-right.
+  #[cfg(feature = "bidi")]
+  #[must_use]
+  pub const fn is_ltr(self) -> bool {
+    !self.is_rtl()
+  }
+
+  /// Returns `true` if this embedding level is right-to-left.
+  #[must_use]
+  pub const fn is_rtl(self) -> bool {
     self.0 % 2 == 1
   }
 
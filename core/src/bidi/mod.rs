@@ -14,15 +14,17 @@ const BIDI_DICTIONARY_COUNT: u16 = BIDI.u16_at(2);
 const BIDI_BRACKETS_COUNT: u16 = ((BIDI_DICTIONARY_OFFSET - 4) / 5) - 1;
 
 mod brackets;
+#[cfg(feature = "bidi")]
+mod info;
 mod level;
 mod paragraph;
 
 use brackets::{BracketPair, OpeningBracket};
 use paragraph::OverrideStatus;
 
+#[cfg(feature = "bidi")]
+pub use info::BidiInfo;
 pub use level::Level;
-#[cfg(test)]
 #[allow(clippy::redundant_pub_crate)]
 pub(super) use paragraph::IsolatingRunSequence;
-#[allow(clippy::redundant_pub_crate)]
-pub(super) use paragraph::Paragraph;
+pub use paragraph::Paragraph;
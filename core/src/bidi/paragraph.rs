@@ -393,6 +393,10 @@ impl IsolatingRunSequence {
   }
 }
 
+/// A paragraph within a string, as resolved by unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text). See [`BidiInfo`][super::super::BidiInfo] for more information.
+#[derive(Clone)]
+#[cfg_attr(feature = "bidi", derive(Debug))]
+#[cfg_attr(docsrs, doc(cfg(feature = "bidi")))]
 pub struct Paragraph {
   pub(in super::super) range: Range<usize>,
   pub(in super::super) level: Level,
@@ -401,6 +405,20 @@ pub struct Paragraph {
 }
 
 impl Paragraph {
+  /// Returns the byte range of this paragraph within the string, including its paragraph separator (if any).
+  #[cfg(feature = "bidi")]
+  #[must_use]
+  pub fn range(&self) -> Range<usize> {
+    self.range.clone()
+  }
+
+  /// Returns the base embedding level of this paragraph.
+  #[cfg(feature = "bidi")]
+  #[must_use]
+  pub const fn level(&self) -> Level {
+    self.level
+  }
+
   pub(in super::super) fn sliced<'a, T: Index<Range<usize>> + ?Sized>(
     &'a self,
     slicable: &'a T,
@@ -419,9 +437,12 @@ impl Paragraph {
     &self,
     text: &str,
     original_classes: &[Class],
-    levels: &[Level],
-  ) -> Result<(Vec<Level>, Vec<Range<usize>>), Error> {
-    let mut levels = Vec::from(levels);
+    original_levels: &[Level],
+    levels: &mut Vec<Level>,
+    runs: &mut Vec<Range<usize>>,
+  ) -> Result<(), Error> {
+    levels.clear();
+    levels.extend_from_slice(original_levels);
 
     let mut reset_from = Some(0);
     let mut reset_to = None;
@@ -474,7 +495,8 @@ impl Paragraph {
       }
     }
 
-    let mut runs = Vec::with_capacity(1);
+    runs.clear();
+
     let mut start = self.range.start;
     let mut run_level = levels[start];
     let mut min_level = run_level;
@@ -524,7 +546,7 @@ impl Paragraph {
       max_level.lower(1)?;
     }
 
-    Ok((levels, runs))
+    Ok(())
   }
 
   #[allow(clippy::too_many_lines)]
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+#[cfg(feature = "leetspeak")]
+use super::leetspeak;
+use super::{CuredStr, CuredString, similar};
+use std::{
+  hash::{Hash, Hasher},
+  ops::Deref,
+};
+
+#[cfg(feature = "leetspeak")]
+fn leetspeak_at(cured: &CuredStr<'_>, haystack: &str) -> Option<(char, usize)> {
+  #[cfg(feature = "options")]
+  if cured.disable_leetspeak {
+    return None;
+  }
+
+  #[cfg(feature = "options")]
+  let regexes = if cured.disable_alphabetical_leetspeak {
+    &leetspeak::NON_ALPHABETICAL_REGEXES
+  } else {
+    &leetspeak::REGEXES
+  };
+
+  #[cfg(not(feature = "options"))]
+  let regexes = &leetspeak::REGEXES;
+
+  leetspeak::find_any(
+    cured.leetspeak_rules.as_deref(),
+    regexes,
+    haystack.as_bytes(),
+  )
+}
+
+// characters that are similar to a letter or a digit are never separators, since they may be used in place of one.
+#[cfg(feature = "separators")]
+fn is_separator(
+  cured: &CuredStr<'_>,
+  character: char,
+  first: Option<char>,
+  run_length: usize,
+) -> bool {
+  !similar::representative(character).is_alphanumeric()
+    && cured.separators.allows(character, first, run_length)
+}
+
+// separators are removed before leetspeak spellings are collapsed, since comparisons skip them as well (e.g: `v.v` has the same key as `vv`, which is a spelling of `w`).
+// leetspeak spellings are never treated as separators, and spellings made of symbols and separators (e.g: `|-|`) are collapsed beforehand since removing the separators would break them. letters and digits around a separator are more likely to be separated characters though (e.g: `l-l`).
+#[cfg(feature = "separators")]
+fn remove_separators(cured: &CuredStr<'_>) -> String {
+  let mut output = String::with_capacity(cured.len());
+  let mut first = None;
+  let mut run_length = 0;
+  let mut index = 0;
+
+  while let Some(character) = cured[index..].chars().next() {
+    #[cfg(feature = "leetspeak")]
+    if let Some((letter, size)) = leetspeak_at(cured, &cured[index..]) {
+      let separator = |character| is_separator(cured, character, None, 0);
+      let spelling = &cured[index..index + size];
+      let trimmed = spelling.trim_end_matches(separator);
+
+      // trailing separators are left out if the spelling is still a spelling without them (e.g: `()_` is `()` followed by a separator).
+      if !trimmed.contains(separator)
+        && leetspeak_at(cured, trimmed).is_some_and(|(_, size)| size == trimmed.len())
+      {
+        output.push_str(trimmed);
+        index += trimmed.len();
+        first = None;
+        run_length = 0;
+
+        continue;
+      } else if !spelling.contains(char::is_alphanumeric)
+        && (!spelling.starts_with(separator)
+          || leetspeak_at(cured, cured[index..].trim_start_matches(separator)).is_none())
+      {
+        output.push(letter);
+        index += size;
+        first = None;
+        run_length = 0;
+
+        continue;
+      }
+    }
+
+    index += character.len_utf8();
+
+    if is_separator(cured, character, first, run_length) {
+      first.get_or_insert(character);
+      run_length += 1;
+    } else {
+      output.push(character);
+      first = None;
+      run_length = 0;
+    }
+  }
+
+  output
+}
+
+pub fn canonical_key(cured: &CuredStr<'_>) -> String {
+  #[cfg(feature = "separators")]
+  let stripped = remove_separators(cured);
+
+  #[cfg(not(feature = "separators"))]
+  let stripped: &str = cured;
+
+  let mut key = String::with_capacity(stripped.len());
+  let mut last = None;
+  let mut index = 0;
+
+  while let Some(character) = stripped[index..].chars().next() {
+    // a spelling is skipped if another one starts within it and reaches further, which is more likely to be the intended one (e.g: `110` is `1` followed by `10`, a spelling of `p`, rather than `11`, a spelling of `m`, followed by `0`).
+    #[cfg(feature = "leetspeak")]
+    let (character, size) = leetspeak_at(cured, &stripped[index..])
+      .filter(|&(_, size)| {
+        let next = index + character.len_utf8();
+
+        !stripped[index..index + size]
+          .chars()
+          .all(char::is_alphanumeric)
+          || leetspeak_at(cured, &stripped[next..])
+            .is_none_or(|(_, next_size)| next + next_size <= index + size)
+      })
+      .unwrap_or_else(|| (character, character.len_utf8()));
+
+    #[cfg(not(feature = "leetspeak"))]
+    let size = character.len_utf8();
+
+    index += size;
+
+    let character = similar::representative(character);
+
+    // repeated characters are treated as one.
+    if last != Some(character) {
+      key.push(character);
+      last = Some(character);
+    }
+  }
+
+  key
+}
+
+/// A wrapper around a [`CuredString`] whose [`Hash`] and [`Eq`] implementations are based on decancer's notion of similarity.
+///
+/// [`CuredString`]'s own [`Hash`] implementation only considers the string itself, which makes it unsuitable as a key of a [`HashMap`][std::collections::HashMap] or a [`HashSet`][std::collections::HashSet] for finding similar-looking strings. This wrapper compares and hashes the [canonical key][CuredString::canonical_key] instead, which allows looking up similar-looking strings in constant time.
+///
+/// **NOTE:** Unlike [`CuredString`]'s comparisons, which aren't symmetric, two wrappers are equal if and only if their canonical keys are equal. This is looser than [`CuredString`]'s comparisons in most regards (e.g: `"helo"` and `"hello"` are equal here), but not all of them: two [equal][CuredString::eq] strings may still have different keys in rare cases, so this is not a substitute for comparing against every string. See [`CuredString::canonical_key`] for more information.
+///
+/// ```rust
+/// use decancer::SimilarKey;
+/// use std::collections::HashSet;
+///
+/// let mut usernames = HashSet::new();
+///
+/// assert!(usernames.insert(SimilarKey::new(decancer::cure!("hello").unwrap())));
+/// assert!(usernames.insert(SimilarKey::new(decancer::cure!("world").unwrap())));
+/// assert!(!usernames.insert(SimilarKey::new(decancer::cure!("|-|3LL0").unwrap())));
+/// assert!(!usernames.insert(SimilarKey::new(decancer::cure!("ｗ.ｏ.ｒ.ｌ.ｄ").unwrap())));
+/// assert!(!usernames.insert(SimilarKey::new(decancer::cure!("helo").unwrap())));
+///
+/// assert_eq!(usernames.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SimilarKey {
+  cured: CuredString,
+  key: String,
+}
+
+impl SimilarKey {
+  /// Wraps a cured string, computing its [canonical key][CuredString::canonical_key].
+  #[must_use]
+  pub fn new(cured: CuredString) -> Self {
+    Self {
+      key: canonical_key(&cured),
+      cured,
+    }
+  }
+
+  /// Returns the [canonical key][CuredString::canonical_key] of the wrapped cured string.
+  #[must_use]
+  pub fn key(&self) -> &str {
+    &self.key
+  }
+
+  /// Unwraps this wrapper, returning the wrapped cured string.
+  #[must_use]
+  pub fn into_inner(self) -> CuredString {
+    self.cured
+  }
+}
+
+impl From<CuredString> for SimilarKey {
+  fn from(cured: CuredString) -> Self {
+    Self::new(cured)
+  }
+}
+
+impl Deref for SimilarKey {
+  type Target = CuredString;
+
+  fn deref(&self) -> &Self::Target {
+    &self.cured
+  }
+}
+
+impl PartialEq for SimilarKey {
+  /// Checks if both cured strings have the same [canonical key][CuredString::canonical_key].
+  fn eq(&self, other: &Self) -> bool {
+    self.key == other.key
+  }
+}
+
+impl Eq for SimilarKey {}
+
+impl Hash for SimilarKey {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.key.hash(state);
+  }
+}
@@ -3,6 +3,8 @@
 
 #[cfg(feature = "options")]
 use super::Options;
+#[cfg(feature = "lookup_table")]
+use super::lookup;
 use super::{
   similar::SIMILAR_START,
   translation::Translation,
@@ -21,6 +23,7 @@ pub const CODEPOINTS_COUNT: u16 = ((CASE_SENSITIVE_CODEPOINTS_OFFSET - 6) / 6) -
 const STRING_TRANSLATION_MASK: u32 = 0x1000_0000;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
 #[cfg_attr(not(feature = "options"), allow(dead_code))]
 pub struct Codepoint(u32, u8, u8);
 
@@ -58,29 +61,75 @@ impl Codepoint {
     )
   }
 
-  pub(super) const fn matches(
-    self,
-    other: u32,
-    #[cfg(feature = "options")] options: Options,
-  ) -> Option<Ordering> {
+  const fn matches(self, other: u32) -> Ordering {
     let mut conf = self.get_codepoint();
 
     if other < conf {
-      return Some(Ordering::Less);
+      return Ordering::Less;
     }
 
     conf += self.range_size();
 
     if other > conf {
-      return Some(Ordering::Greater);
+      return Ordering::Greater;
     }
 
-    #[cfg(feature = "options")]
-    if options.refuse_cure(self.2) {
-      return None;
+    Ordering::Equal
+  }
+
+  #[cfg(feature = "lookup_table")]
+  pub(super) const fn search(code: u32, offset: i32, end: i32) -> Option<Self> {
+    lookup::search(code, offset, end)
+  }
+
+  #[cfg(not(feature = "lookup_table"))]
+  pub(super) const fn search(code: u32, offset: i32, end: i32) -> Option<Self> {
+    Self::binary_search(code, offset, end)
+  }
+
+  #[cfg_attr(all(feature = "lookup_table", not(test)), allow(dead_code))]
+  pub(super) const fn binary_search(code: u32, offset: i32, mut end: i32) -> Option<Self> {
+    let mut start = 0;
+
+    while start <= end {
+      let mid = start.midpoint(end);
+      let codepoint = Self::at(offset + (mid * 6));
+
+      match codepoint.matches(code) {
+        Ordering::Equal => return Some(codepoint),
+
+        Ordering::Greater => start = mid + 1,
+
+        Ordering::Less => end = mid - 1,
+      }
     }
 
-    Some(Ordering::Equal)
+    None
+  }
+
+  // returns every codepoint that is known by decancer, in no particular order.
+  pub(super) fn all() -> impl Iterator<Item = u32> {
+    [
+      (6, CODEPOINTS_COUNT),
+      (
+        CASE_SENSITIVE_CODEPOINTS_OFFSET,
+        CASE_SENSITIVE_CODEPOINTS_COUNT,
+      ),
+    ]
+    .into_iter()
+    .flat_map(|(offset, end)| {
+      (0..=i32::from(end)).flat_map(move |idx| {
+        let codepoint = Self::at(i32::from(offset) + (idx * 6));
+        let start = codepoint.get_codepoint();
+
+        start..=start + codepoint.range_size()
+      })
+    })
+  }
+
+  #[cfg(feature = "options")]
+  pub(super) const fn is_retained(self, options: Options) -> bool {
+    options.refuse_cure(self.2)
   }
 
   pub(super) fn translation(
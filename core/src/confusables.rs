@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{
+  script::ScriptSet,
+  util::{Binary, CODEPOINT_MASK},
+};
+use std::str;
+
+const CONFUSABLES: Binary = Binary::new(include_bytes!("../bin/confusables.bin"));
+
+const DECOMPOSITIONS_OFFSET: u16 = CONFUSABLES.u16_at(0);
+const COMBINING_CLASSES_OFFSET: u16 = CONFUSABLES.u16_at(2);
+const STRINGS_OFFSET: u16 = CONFUSABLES.u16_at(4);
+
+// - 1 because we're only using them in binary search
+const CONFUSABLES_COUNT: u16 = ((DECOMPOSITIONS_OFFSET - 6) / 6) - 1;
+const DECOMPOSITIONS_COUNT: u16 = ((COMBINING_CLASSES_OFFSET - DECOMPOSITIONS_OFFSET) / 6) - 1;
+const COMBINING_CLASSES_COUNT: u16 = ((STRINGS_OFFSET - COMBINING_CLASSES_OFFSET) / 6) - 1;
+
+const HANGUL_START: u32 = 0xac00;
+const HANGUL_END: u32 = 0xd7a3;
+const HANGUL_L_START: u32 = 0x1100;
+const HANGUL_V_START: u32 = 0x1161;
+const HANGUL_T_START: u32 = 0x11a7;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn search(code: u32, offset: u16, count: u16) -> Option<usize> {
+  let mut start = 0i32;
+  let mut end = count as i32;
+
+  while start <= end {
+    let mid = start.midpoint(end);
+    let offset = ((offset as i32) + (mid * 6)) as usize;
+
+    if code < (CONFUSABLES.u32_at(offset) & CODEPOINT_MASK) {
+      end = mid - 1;
+    } else if code > (CONFUSABLES.u32_at(offset) & CODEPOINT_MASK) {
+      start = mid + 1;
+    } else {
+      return Some(offset);
+    }
+  }
+
+  None
+}
+
+// returns the NFD string that a confusable or a decomposition record points to.
+fn string_at(offset: usize) -> &'static str {
+  let size = (CONFUSABLES.u32_at(offset) >> 20) as usize;
+  let start = STRINGS_OFFSET as usize + CONFUSABLES.u16_at(offset + 4) as usize;
+
+  str::from_utf8(CONFUSABLES.sliced(start, size)).unwrap()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn combining_class(code: u32) -> u8 {
+  let mut start = 0i32;
+  let mut end = COMBINING_CLASSES_COUNT as i32;
+
+  while start <= end {
+    let mid = start.midpoint(end);
+    let offset = ((COMBINING_CLASSES_OFFSET as i32) + (mid * 6)) as _;
+    let kv = CONFUSABLES.u32_at(offset);
+
+    let other = kv & CODEPOINT_MASK;
+
+    if code < other {
+      end = mid - 1;
+    } else if code > (other + CONFUSABLES.u16_at(offset + 4) as u32) {
+      start = mid + 1;
+    } else {
+      return (kv >> 20) as _;
+    }
+  }
+
+  0
+}
+
+fn decompose(character: char, output: &mut Vec<char>) {
+  let code = character as u32;
+
+  if (HANGUL_START..=HANGUL_END).contains(&code) {
+    let index = code - HANGUL_START;
+    let t = index % HANGUL_T_COUNT;
+
+    output.extend(
+      [
+        HANGUL_L_START + index / (HANGUL_V_COUNT * HANGUL_T_COUNT),
+        HANGUL_V_START + (index % (HANGUL_V_COUNT * HANGUL_T_COUNT)) / HANGUL_T_COUNT,
+      ]
+      .into_iter()
+      .chain((t != 0).then_some(HANGUL_T_START + t))
+      .filter_map(char::from_u32),
+    );
+  } else if let Some(offset) = search(code, DECOMPOSITIONS_OFFSET, DECOMPOSITIONS_COUNT) {
+    output.extend(string_at(offset).chars());
+  } else {
+    output.push(character);
+  }
+}
+
+// unicode's canonical decomposition (NFD), including canonical ordering.
+fn nfd<I: IntoIterator<Item = char>>(input: I) -> Vec<char> {
+  let mut output = vec![];
+
+  for character in input {
+    decompose(character, &mut output);
+  }
+
+  let mut start = 0;
+
+  while start < output.len() {
+    let end = output[start..]
+      .iter()
+      .position(|&character| combining_class(character as _) == 0)
+      .map_or(output.len(), |position| start + position);
+
+    output[start..end].sort_by_key(|&character| combining_class(character as _));
+    start = end + 1;
+  }
+
+  output
+}
+
+/// Returns the [skeleton](https://www.unicode.org/reports/tr39/#Confusable_Detection) of a string as defined by unicode, which is the same for strings that are visually confusable.
+///
+/// Unlike [`cure`][super::cure()], this follows unicode's `confusables.txt` data and algorithm exactly, so that the output is identical to other implementations of it (e.g: ICU's `uspoof_getSkeleton`). The output is not meant to be displayed, as it's only useful for comparing with other skeletons.
+///
+/// ```rust
+/// assert_eq!(decancer::skeleton("p\u{0430}ypal"), decancer::skeleton("paypal"));
+/// assert_ne!(decancer::skeleton("hello"), decancer::skeleton("world"));
+/// ```
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "confusables")))]
+pub fn skeleton(input: &str) -> String {
+  let mut output = vec![];
+
+  for character in nfd(input.chars()) {
+    match search(character as _, 6, CONFUSABLES_COUNT) {
+      Some(offset) => output.extend(string_at(offset).chars()),
+      None => output.push(character),
+    }
+  }
+
+  nfd(output).into_iter().collect()
+}
+
+/// The kind of confusable that two strings are, as defined by [unicode](https://www.unicode.org/reports/tr39/#Confusable_Detection). See [`are_confusable`] for more information.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "confusables")))]
+pub enum ConfusableKind {
+  /// Both strings have at least one script in common (e.g: `"ǉeto"` and `"ljeto"`).
+  SingleScript,
+
+  /// The strings have no script in common, and at least one of them mixes scripts (e.g: `"paypal"` and `"p\u{0430}ypal"`).
+  MixedScript,
+
+  /// The strings have no script in common, and each of them is from a single script (e.g: `"scope"` and its cyrillic lookalike `"ѕсоре"`).
+  WholeScript,
+}
+
+/// Returns whether two strings are visually confusable (i.e. they have the same [`skeleton`]), and if so, what kind of confusable they are.
+///
+/// Scripts are compared with unicode's `Script_Extensions` property, where characters shared by multiple scripts (like digits and punctuation) are compatible with every script. Identical strings are considered confusable as well.
+///
+/// ```rust
+/// use decancer::ConfusableKind;
+///
+/// assert_eq!(decancer::are_confusable("ǉeto", "ljeto"), Some(ConfusableKind::SingleScript));
+/// assert_eq!(decancer::are_confusable("paypal", "p\u{0430}ypal"), Some(ConfusableKind::MixedScript));
+/// assert_eq!(decancer::are_confusable("scope", "ѕсоре"), Some(ConfusableKind::WholeScript));
+/// assert_eq!(decancer::are_confusable("hello", "world"), None);
+/// ```
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "confusables")))]
+pub fn are_confusable(a: &str, b: &str) -> Option<ConfusableKind> {
+  if skeleton(a) != skeleton(b) {
+    return None;
+  }
+
+  let a = ScriptSet::resolved(a);
+  let b = ScriptSet::resolved(b);
+
+  Some(if !a.intersection(b).is_empty() {
+    ConfusableKind::SingleScript
+  } else if a.is_empty() || b.is_empty() {
+    ConfusableKind::MixedScript
+  } else {
+    ConfusableKind::WholeScript
+  })
+}
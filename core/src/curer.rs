@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{
+  Class, CuredString, Error, Level, Options, Paragraph,
+  bidi::IsolatingRunSequence,
+  overrides::{self, Overrides},
+};
+use std::ops::Range;
+
+#[derive(Default)]
+pub struct Scratch {
+  pub(super) refined_input: String,
+  pub(super) original_classes: Vec<Class>,
+  pub(super) isolate_stack: Vec<usize>,
+  pub(super) paragraphs: Vec<Paragraph>,
+  pub(super) levels: Vec<Level>,
+  pub(super) level_runs: Vec<Range<usize>>,
+  pub(super) processing_classes: Vec<Class>,
+  pub(super) sequences: Vec<IsolatingRunSequence>,
+  pub(super) revised_levels: Vec<Level>,
+  pub(super) visual_runs: Vec<Range<usize>>,
+}
+
+/// A reusable curer that keeps its scratch memory across calls.
+///
+/// Every call to [`cure`][super::cure()] allocates several intermediate buffers for [bidirectional reordering](https://en.wikipedia.org/wiki/Bidirectional_text). This keeps those buffers around instead, which avoids most allocations when curing a lot of strings (e.g: every message in a chat). Use [`cure_into`][Curer::cure_into] to also reuse the output buffer.
+///
+/// ```rust
+/// use decancer::Curer;
+///
+/// let mut curer = Curer::new(decancer::Options::default());
+/// let mut output = String::new();
+///
+/// for input in ["vＥⓡ𝔂 𝔽𝕌Ňℕｙ", " ", "ţ乇𝕏𝓣"] {
+///   curer.cure_into(input, &mut output).unwrap();
+/// }
+///
+/// assert_eq!(output, "very funny text");
+/// assert_eq!(curer.cure("Ｈ𝑒ｌｌ𝑜").unwrap(), "hello");
+/// ```
+pub struct Curer {
+  options: Options,
+  scratch: Scratch,
+}
+
+impl Curer {
+  /// Creates a new reusable curer with the specified [`Options`].
+  #[must_use]
+  pub fn new(options: Options) -> Self {
+    Self {
+      options,
+      scratch: Scratch::default(),
+    }
+  }
+
+  /// Returns the [`Options`] used by this curer.
+  #[must_use]
+  pub const fn options(&self) -> Options {
+    self.options
+  }
+
+  pub(super) fn cure_into_with(
+    &mut self,
+    input: &str,
+    output: &mut String,
+    overrides: &Overrides,
+  ) -> Result<(), Error> {
+    let length = output.len();
+    let result = super::cure_into_with(input, self.options, overrides, &mut self.scratch, output);
+
+    if result.is_err() {
+      output.truncate(length);
+    }
+
+    result
+  }
+
+  /// Cures a string and appends the result to `output`, reusing this curer's scratch memory. `output` is left untouched if this function errors.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+  pub fn cure_into(&mut self, input: &str, output: &mut String) -> Result<(), Error> {
+    self.cure_into_with(input, output, &overrides::EMPTY)
+  }
+
+  /// Cures a string, reusing this curer's scratch memory.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+  pub fn cure(&mut self, input: &str) -> Result<CuredString, Error> {
+    let mut output = String::with_capacity(input.len());
+
+    self.cure_into(input, &mut output)?;
+
+    Ok(CuredString::new(output, self.options))
+  }
+}
+
+impl Clone for Curer {
+  /// Creates a new curer with the same [`Options`]. The scratch memory is not cloned.
+  fn clone(&self) -> Self {
+    Self::new(self.options)
+  }
+}
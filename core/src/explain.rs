@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{CuredString, Report, Translation};
+use std::ops::Range;
+
+/// Where the translation of a single character came from.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CureSource {
+  /// The character was translated by an [`Overrides`][super::Overrides] table.
+  Override,
+
+  /// The character is an ASCII character, which is only turned into lowercase.
+  Ascii,
+
+  /// The character was translated by decancer's table of uppercase characters.
+  CaseSensitiveTable,
+
+  /// The character was translated by decancer's main table.
+  MainTable,
+
+  /// The character was left as is, either because decancer doesn't know about it or because one of the `retain_*` options prevented it from being cured.
+  Unchanged,
+
+  /// The character is a whitespace character, which is folded into a regular space.
+  Whitespace,
+
+  /// The translation was removed by [`Options::ascii_only`][super::Options::ascii_only] or [`Options::alphanumeric_only`][super::Options::alphanumeric_only].
+  Stripped,
+
+  /// The character is a [control character](https://en.wikipedia.org/wiki/Control_character), [surrogate](https://en.wikipedia.org/wiki/Universal_Character_Set_characters#Surrogates), [private use character](https://en.wikipedia.org/wiki/Private_Use_Areas) or any other character that decancer always removes.
+  Removed,
+
+  /// The character is a [bidirectional control character](https://en.wikipedia.org/wiki/Bidirectional_text#Explicit_formatting). By default, it is applied by bidirectional reordering and then kept as is in the cured string. If [`Options::disable_bidi`][super::Options::disable_bidi] is enabled, it is removed instead and its [`cured`][CureStep::cured] range is empty.
+  BidiControl,
+}
+
+/// A record of how a single character of the input was cured.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CureStep {
+  /// The character from the input.
+  pub character: char,
+
+  /// The non-inclusive range of this character in the input.
+  pub original: Range<usize>,
+
+  /// The non-inclusive range of this character's translation in the cured string. This range is empty if the character was removed, in which case it points to where the previous character of the input ends.
+  pub cured: Range<usize>,
+
+  /// The translation that was applied to this character.
+  pub translation: Translation,
+
+  /// Where this translation came from.
+  pub source: CureSource,
+
+  /// Whether this character was part of a sequence that was reversed by [bidirectional reordering](https://en.wikipedia.org/wiki/Bidirectional_text).
+  pub reversed: bool,
+}
+
+/// A cured string along with a record of how every character of the input was cured.
+///
+/// This is returned by [`cure_explained`][super::cure_explained()].
+#[derive(Clone, Debug)]
+pub struct Explanation {
+  /// The cured string.
+  pub cured: CuredString,
+
+  /// A record of how every character of the input was cured, in the same order as they appear in the input.
+  pub steps: Vec<CureStep>,
+}
+
+impl Explanation {
+  pub(super) fn new(cured: CuredString, mut steps: Vec<CureStep>, original: &str) -> Self {
+    steps.sort_unstable_by_key(|step| step.original.start);
+
+    let mut visited = steps.into_iter().peekable();
+    let mut steps = Vec::with_capacity(original.len());
+    let mut last_end = 0;
+
+    for (idx, character) in original.char_indices() {
+      match visited.next_if(|step| step.original.start == idx) {
+        Some(step) => {
+          last_end = step.cured.end;
+          steps.push(step);
+        },
+
+        // characters removed before bidirectional reordering are never visited, so they are added here.
+        None => steps.push(CureStep {
+          character,
+          original: idx..idx + character.len_utf8(),
+          cured: last_end..last_end,
+          translation: Translation::None,
+          source: CureSource::Removed,
+          reversed: false,
+        }),
+      }
+    }
+
+    Self { cured, steps }
+  }
+
+  /// Returns a report of how disguised the input is. See [`Report`] for more information.
+  #[must_use]
+  pub fn report(&self) -> Report {
+    Report::new(&self.steps)
+  }
+}
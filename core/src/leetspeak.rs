@@ -1,9 +1,18 @@
 // SPDX-License-Identifier: MIT
 // SPDX-FileCopyrightText: 2021-2026 null8626
 
-use std::sync::LazyLock;
+use super::similar::to_lowercase;
+use std::{
+  cmp::Reverse,
+  fmt::{self, Debug, Formatter},
+  hash::{Hash, Hasher},
+  sync::LazyLock,
+};
 
-use regex::bytes::{Regex, RegexBuilder};
+use regex::{
+  Error,
+  bytes::{Regex, RegexBuilder},
+};
 
 macro_rules! re {
   ($pattern:literal) => {{
@@ -94,15 +103,144 @@ pub static NON_ALPHABETICAL_REGEXES: LazyLock<[Option<Regex>; 26]> = LazyLock::n
   ]
 });
 
+/// A set of custom leetspeak rules that can be used by a [`CuredString`][super::CuredString]'s comparison methods.
+///
+/// Each rule is a [regular expression](https://docs.rs/regex/latest/regex/#syntax) that matches a leetspeak spelling of a single target character, which can be any character (e.g: a digit or a non-latin letter). By default, these rules are consulted before decancer's built-in leetspeak rules, and rules registered earlier take precedence over ones registered later.
+///
+/// ```rust
+/// use decancer::LeetspeakRules;
+///
+/// let rules = LeetspeakRules::new().add('f', "ph").unwrap();
+/// let mut cured = decancer::cure!("phun").unwrap();
+///
+/// assert_ne!(cured, "fun");
+///
+/// cured.leetspeak_rules(rules);
+///
+/// assert_eq!(cured, "fun");
+/// ```
+#[derive(Clone)]
+pub struct LeetspeakRules {
+  rules: Vec<(char, Regex)>,
+  defaults: bool,
+}
+
+impl LeetspeakRules {
+  /// Creates a new set of rules that extends decancer's built-in leetspeak rules.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self {
+      rules: vec![],
+      defaults: true,
+    }
+  }
+
+  /// Creates a new set of rules that replaces decancer's built-in leetspeak rules entirely.
+  #[must_use]
+  pub const fn empty() -> Self {
+    Self {
+      rules: vec![],
+      defaults: false,
+    }
+  }
+
+  /// Registers a new rule that matches a leetspeak spelling of a character. The pattern is always matched at the start of the rest of the string.
+  ///
+  /// This comparison is case-insensitive with regards to the target character.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the pattern is not a valid regular expression.
+  pub fn add(mut self, character: char, pattern: &str) -> Result<Self, Error> {
+    self.rules.push((
+      to_lowercase(character),
+      Regex::new(&format!("^(?:{pattern})"))?,
+    ));
+
+    Ok(self)
+  }
+}
+
+impl Default for LeetspeakRules {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Debug for LeetspeakRules {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_struct("LeetspeakRules")
+      .field(
+        "rules",
+        &self
+          .rules
+          .iter()
+          .map(|(character, regex)| (character, regex.as_str()))
+          .collect::<Vec<_>>(),
+      )
+      .field("defaults", &self.defaults)
+      .finish()
+  }
+}
+
+impl PartialEq for LeetspeakRules {
+  fn eq(&self, other: &Self) -> bool {
+    self.defaults == other.defaults
+      && self.rules.len() == other.rules.len()
+      && self
+        .rules
+        .iter()
+        .zip(&other.rules)
+        .all(|((a, a_regex), (b, b_regex))| a == b && a_regex.as_str() == b_regex.as_str())
+  }
+}
+
+impl Eq for LeetspeakRules {}
+
+impl Hash for LeetspeakRules {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.defaults.hash(state);
+
+    for (character, regex) in &self.rules {
+      character.hash(state);
+      regex.as_str().hash(state);
+    }
+  }
+}
+
 pub fn find(
+  rules: Option<&LeetspeakRules>,
   regexes: &LazyLock<[Option<Regex>; 26]>,
   haystack: &[u8],
-  character: u32,
+  character: char,
 ) -> Option<usize> {
+  if let Some(rules) = rules {
+    let character = to_lowercase(character);
+
+    // empty matches would never advance the matcher, and matches that end in the middle of a character can't be used either.
+    if let Some(mat) = rules
+      .rules
+      .iter()
+      .filter(|(target, _)| *target == character)
+      .find_map(|(_, regex)| {
+        regex.find(haystack).filter(|mat| {
+          !mat.is_empty()
+            && haystack
+              .get(mat.end())
+              .is_none_or(|&byte| (byte & 0xc0) != 0x80)
+        })
+      })
+    {
+      return Some(mat.len());
+    } else if !rules.defaults {
+      return None;
+    }
+  }
+
   regexes[match character {
-    65..=90 => character - 65,
+    'A'..='Z' => character as u32 - 65,
 
-    97..=122 => character - 97,
+    'a'..='z' => character as u32 - 97,
 
     _ => return None,
   } as usize]
@@ -110,3 +248,17 @@ pub fn find(
     .find(haystack)
     .map(|mat| mat.len())
 }
+
+// returns the character with the longest leetspeak spelling at the start of the haystack (e.g: `\/\/` is `w` rather than `v`), along with its length. custom rules are consulted first on ties.
+pub fn find_any(
+  rules: Option<&LeetspeakRules>,
+  regexes: &LazyLock<[Option<Regex>; 26]>,
+  haystack: &[u8],
+) -> Option<(char, usize)> {
+  rules
+    .into_iter()
+    .flat_map(|rules| rules.rules.iter().map(|&(character, _)| character))
+    .chain('a'..='z')
+    .filter_map(|character| find(rules, regexes, haystack, character).map(|len| (character, len)))
+    .min_by_key(|&(_, len)| Reverse(len))
+}
@@ -5,30 +5,82 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod bidi;
+mod canonical;
 mod codepoints;
+#[cfg(feature = "confusables")]
+mod confusables;
+mod curer;
+mod explain;
 #[cfg(feature = "leetspeak")]
 mod leetspeak;
+#[cfg(feature = "lookup_table")]
+mod lookup;
+mod mapped;
 mod options;
+mod overrides;
+mod policy;
+mod report;
+mod restriction;
+mod reverse;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod roundtrip;
+mod script;
+#[cfg(feature = "separators")]
+mod separators;
+mod set;
 mod similar;
+mod stream;
 mod string;
 #[cfg(test)]
 mod tests;
 mod translation;
 mod util;
-
-use bidi::{Class, Level, Paragraph};
+mod variants;
+
+use bidi::Class;
+#[cfg(feature = "bidi")]
+pub use bidi::{BidiInfo, Level, Paragraph};
+#[cfg(not(feature = "bidi"))]
+use bidi::{Level, Paragraph};
+pub use canonical::SimilarKey;
+#[cfg(feature = "confusables")]
+pub use confusables::{ConfusableKind, are_confusable, skeleton};
+pub use curer::Curer;
+use curer::Scratch;
+pub use explain::{CureSource, CureStep, Explanation};
+#[cfg(feature = "leetspeak")]
+pub use leetspeak::LeetspeakRules;
+pub use mapped::MappedCuredString;
 pub use options::Options;
-pub use similar::Matcher;
-pub use string::CuredString;
+#[cfg(feature = "options")]
+pub use options::{OptionFlag, ParseOptionsError};
+pub use overrides::Overrides;
+pub use policy::{IdentifierPolicy, Violation};
+pub use report::Report;
+pub use restriction::RestrictionLevel;
+pub use reverse::reverse_cure;
+pub use script::{Script, scripts};
+#[cfg(feature = "separators")]
+pub use separators::Separators;
+pub use set::MatcherSet;
+pub use similar::{Match, Matcher, is_similar, similar_chars, similar_groups};
+pub use stream::{CuredReader, StreamCurer};
+#[cfg(feature = "serde")]
+pub use string::CuredStringSeed;
+pub use string::{CuredStr, CuredString};
 pub use translation::Translation;
+pub use variants::{Variants, variants};
+
+use std::ops::Range;
 
 use codepoints::{
   CASE_SENSITIVE_CODEPOINTS_COUNT, CASE_SENSITIVE_CODEPOINTS_OFFSET, CODEPOINTS_COUNT,
 };
 
-use util::{error_enum, is_none};
 #[cfg(feature = "options")]
-use util::{is_alphanumeric, is_special_rtl};
+use util::is_alphanumeric;
+use util::{error_enum, is_cured_ascii, is_none, is_special_rtl};
 
 error_enum! {
   /// An error enum for unicode bidi errors caused by malformed string inputs.
@@ -55,11 +107,40 @@ error_enum! {
   }
 }
 
-fn cure_char_inner(code: u32, options: Options) -> Translation {
+#[cfg(feature = "options")]
+fn stripped(
+  translation: Translation,
+  source: CureSource,
+  options: Options,
+) -> (Translation, CureSource) {
+  if translation == Translation::None {
+    return (translation, source);
+  }
+
+  match translation.ensure_stripped_if(options.is(25), options.is(26)) {
+    Translation::None => (Translation::None, CureSource::Stripped),
+
+    translation => (translation, source),
+  }
+}
+
+fn cure_char_inner(
+  code: u32,
+  options: Options,
+  overrides: &Overrides,
+) -> (Translation, CureSource) {
   let code_lowercased = char::from_u32(code)
     .and_then(|character| character.to_lowercase().next())
     .unwrap() as _;
 
+  if let Some(translation) = overrides.translate(code, code_lowercased, options) {
+    #[cfg(feature = "options")]
+    return stripped(translation, CureSource::Override, options);
+
+    #[cfg(not(feature = "options"))]
+    return (translation, CureSource::Override);
+  }
+
   let is_case_sensitive = code != code_lowercased;
 
   #[cfg(feature = "options")]
@@ -84,10 +165,10 @@ fn cure_char_inner(code: u32, options: Options) -> Translation {
   if default_output < 0x80 {
     #[cfg(feature = "options")]
     if alphanumeric_only && !is_alphanumeric(default_output) {
-      return Translation::None;
+      return (Translation::None, CureSource::Stripped);
     }
 
-    return Translation::character(default_output);
+    return (Translation::character(default_output), CureSource::Ascii);
   } else if is_case_sensitive {
     #[cfg_attr(not(feature = "options"), allow(unused_mut))]
     if let Some(mut translation) = options.translate(
@@ -101,10 +182,10 @@ fn cure_char_inner(code: u32, options: Options) -> Translation {
       }
 
       #[cfg(feature = "options")]
-      return translation.ensure_stripped_if(ascii_only, alphanumeric_only);
+      return stripped(translation, CureSource::CaseSensitiveTable, options);
 
       #[cfg(not(feature = "options"))]
-      return translation;
+      return (translation, CureSource::CaseSensitiveTable);
     }
   }
 
@@ -114,9 +195,12 @@ fn cure_char_inner(code: u32, options: Options) -> Translation {
     .map_or_else(
       || {
         if ascii_only || alphanumeric_only {
-          Translation::None
+          (Translation::None, CureSource::Stripped)
         } else {
-          Translation::character(default_output)
+          (
+            Translation::character(default_output),
+            CureSource::Unchanged,
+          )
         }
       },
       |mut translation| {
@@ -124,31 +208,54 @@ fn cure_char_inner(code: u32, options: Options) -> Translation {
           translation.make_uppercase();
         }
 
-        translation.ensure_stripped_if(ascii_only, alphanumeric_only)
+        stripped(translation, CureSource::MainTable, options)
       },
     );
 
   #[cfg(not(feature = "options"))]
   options
     .translate(code_lowercased, 6, CODEPOINTS_COUNT as _)
-    .unwrap_or_else(|| Translation::character(default_output))
+    .map_or_else(
+      || {
+        (
+          Translation::character(default_output),
+          CureSource::Unchanged,
+        )
+      },
+      |translation| (translation, CureSource::MainTable),
+    )
 }
 
 /// Cures a single character/unicode codepoint with the specified [`Options`].
 ///
 /// To use this function with decancer's default options, use [the `cure_char` macro][cure_char!] instead.
 pub fn cure_char<C: Into<u32>>(code: C, options: Options) -> Translation {
-  let code = code.into();
+  cure_char_with(code.into(), options, &overrides::EMPTY)
+}
+
+fn cure_char_with(code: u32, options: Options, overrides: &Overrides) -> Translation {
+  cure_char_traced(code, options, overrides).0
+}
 
-  if is_none(code) {
-    Translation::None
+fn cure_char_traced(
+  code: u32,
+  options: Options,
+  overrides: &Overrides,
+) -> (Translation, CureSource) {
+  if overrides.contains(code) {
+    cure_char_inner(code, options, overrides)
+  } else if is_none(code) {
+    (Translation::None, CureSource::Removed)
   } else {
     match Class::new(code) {
-      Some(Class::WS) => Translation::character(if code > 0x7f { 0x20 } else { code }),
+      Some(Class::WS) => (
+        Translation::character(if code > 0x7f { 0x20 } else { code }),
+        CureSource::Whitespace,
+      ),
 
-      None => Translation::None,
+      None => (Translation::None, CureSource::Removed),
 
-      _ => cure_char_inner(code, options),
+      _ => cure_char_inner(code, options, overrides),
     }
   }
 }
@@ -178,12 +285,30 @@ macro_rules! cure_char {
   };
 }
 
-fn first_cure_pass(input: &str) -> (String, Vec<Class>, Vec<Paragraph>) {
-  let mut refined_input = String::with_capacity(input.len());
-  let mut original_classes = Vec::with_capacity(input.len());
-  let mut isolate_stack = vec![];
+// without overrides, every character is kept as-is (for the public bidi API), so that the byte indices match the input.
+// otherwise, characters that are removed or turned into a space are only kept as-is if they are overridden.
+#[allow(clippy::too_many_lines)]
+fn first_cure_pass(
+  input: &str,
+  mut offsets: Option<&mut Vec<usize>>,
+  overrides: Option<&Overrides>,
+  scratch: &mut Scratch,
+) {
+  let Scratch {
+    refined_input,
+    original_classes,
+    isolate_stack,
+    paragraphs,
+    ..
+  } = scratch;
+
+  refined_input.clear();
+  refined_input.reserve(input.len());
+  original_classes.clear();
+  original_classes.reserve(input.len());
+  isolate_stack.clear();
+  paragraphs.clear();
 
-  let mut paragraphs = vec![];
   let mut paragraph_start = 0;
   let mut paragraph_level = None;
   let mut pure_ltr = true;
@@ -191,91 +316,105 @@ fn first_cure_pass(input: &str) -> (String, Vec<Class>, Vec<Paragraph>) {
 
   let mut idx = 0;
 
-  for codepoint in input.chars() {
+  for (original_idx, codepoint) in input.char_indices() {
     let mut character_len = codepoint.len_utf8();
     let mut codepoint = codepoint as u32;
 
-    if !is_none(codepoint) {
-      if let Some(class) = Class::new(codepoint) {
-        if class == Class::WS && codepoint > 0x7f {
-          character_len = 1;
-          codepoint = 0x20;
-        }
+    let refine = overrides.is_some_and(|overrides| !overrides.contains(codepoint));
 
-        original_classes.resize(original_classes.len() + character_len, class);
+    let class = if !refine {
+      Some(Class::new(codepoint).unwrap_or(Class::L))
+    } else if is_none(codepoint) {
+      None
+    } else {
+      Class::new(codepoint)
+    };
 
-        match class {
-          Class::B => {
-            let paragraph_end = idx + character_len;
+    let Some(class) = class else {
+      continue;
+    };
 
-            paragraphs.push(Paragraph {
-              range: paragraph_start..paragraph_end,
-              level: paragraph_level.unwrap_or(Level::LTR),
-              pure_ltr,
-              has_isolate_controls,
-            });
+    if refine && class == Class::WS && codepoint > 0x7f {
+      character_len = 1;
+      codepoint = 0x20;
+    }
 
-            paragraph_start = paragraph_end;
-            pure_ltr = true;
-            has_isolate_controls = false;
-            isolate_stack.clear();
-            paragraph_level = None;
-          },
+    original_classes.resize(original_classes.len() + character_len, class);
 
-          Class::L | Class::R | Class::AL => {
-            if class != Class::L {
-              pure_ltr = false;
-            }
+    if let Some(offsets) = offsets.as_mut() {
+      offsets.resize(offsets.len() + character_len, original_idx);
+    }
 
-            match isolate_stack.last() {
-              Some(&start_idx) => {
-                if original_classes[start_idx] == Class::FSI {
-                  let new_class = if class == Class::L {
-                    Class::LRI
-                  } else {
-                    Class::RLI
-                  };
-
-                  for j in 0..3 {
-                    original_classes[start_idx + j] = new_class;
-                  }
-                }
-              },
-
-              None => {
-                if paragraph_level.is_none() {
-                  paragraph_level.replace(if class == Class::L {
-                    Level::LTR
-                  } else {
-                    Level::RTL
-                  });
-                }
-              },
-            }
-          },
+    match class {
+      Class::B => {
+        let paragraph_end = idx + character_len;
+
+        paragraphs.push(Paragraph {
+          range: paragraph_start..paragraph_end,
+          level: paragraph_level.unwrap_or(Level::LTR),
+          pure_ltr,
+          has_isolate_controls,
+        });
+
+        paragraph_start = paragraph_end;
+        pure_ltr = true;
+        has_isolate_controls = false;
+        isolate_stack.clear();
+        paragraph_level = None;
+      },
 
-          Class::AN | Class::LRE | Class::RLE | Class::LRO | Class::RLO => {
-            pure_ltr = false;
-          },
+      Class::L | Class::R | Class::AL => {
+        if class != Class::L {
+          pure_ltr = false;
+        }
 
-          Class::RLI | Class::LRI | Class::FSI => {
-            pure_ltr = false;
-            has_isolate_controls = true;
-            isolate_stack.push(idx);
+        match isolate_stack.last() {
+          Some(&start_idx) => {
+            if original_classes[start_idx] == Class::FSI {
+              let new_class = if class == Class::L {
+                Class::LRI
+              } else {
+                Class::RLI
+              };
+
+              for j in 0..3 {
+                original_classes[start_idx + j] = new_class;
+              }
+            }
           },
 
-          Class::PDI => {
-            isolate_stack.pop();
+          None => {
+            if paragraph_level.is_none() {
+              paragraph_level.replace(if class == Class::L {
+                Level::LTR
+              } else {
+                Level::RTL
+              });
+            }
           },
-
-          _ => {},
         }
+      },
 
-        refined_input.push(char::from_u32(codepoint).unwrap());
+      Class::AN | Class::LRE | Class::RLE | Class::LRO | Class::RLO => {
+        pure_ltr = false;
+      },
 
-        idx += character_len;
-      }
+      Class::RLI | Class::LRI | Class::FSI => {
+        pure_ltr = false;
+        has_isolate_controls = true;
+        isolate_stack.push(idx);
+      },
+
+      Class::PDI => {
+        isolate_stack.pop();
+      },
+
+      _ => {},
     }
+
+    refined_input.push(char::from_u32(codepoint).unwrap());
+
+    idx += character_len;
   }
 
   if paragraph_start < idx {
@@ -286,27 +425,36 @@ fn first_cure_pass(input: &str) -> (String, Vec<Class>, Vec<Paragraph>) {
       has_isolate_controls,
     });
   }
-
-  (refined_input, original_classes, paragraphs)
 }
 
-fn cure_reordered(input: &str, options: Options) -> Result<String, Error> {
-  let (refined_input, original_classes, paragraphs) = first_cure_pass(input);
-
-  let mut levels = Vec::with_capacity(refined_input.len());
-  let mut level_runs = vec![];
-  let mut processing_classes = original_classes.clone();
-  let mut output = String::with_capacity(refined_input.len());
-  let mut sequences = vec![];
-
-  for paragraph in &paragraphs {
+fn resolve_levels(scratch: &mut Scratch) -> Result<(), Error> {
+  let Scratch {
+    refined_input,
+    original_classes,
+    paragraphs,
+    levels,
+    level_runs,
+    processing_classes,
+    sequences,
+    ..
+  } = scratch;
+
+  let refined_input: &str = refined_input;
+  let original_classes: &[Class] = original_classes;
+
+  levels.clear();
+  levels.reserve(refined_input.len());
+  processing_classes.clear();
+  processing_classes.extend_from_slice(original_classes);
+
+  for paragraph in paragraphs.iter() {
     levels.resize(levels.len() + paragraph.range.len(), paragraph.level);
 
     if paragraph.level.0 != 0 || !paragraph.pure_ltr {
-      let input = paragraph.sliced(&refined_input);
-      let original_classes = paragraph.sliced(&original_classes);
-      let processing_classes = paragraph.sliced_mut(&mut processing_classes);
-      let levels = paragraph.sliced_mut(&mut levels);
+      let input = paragraph.sliced(refined_input);
+      let original_classes = paragraph.sliced(original_classes);
+      let processing_classes = paragraph.sliced_mut(processing_classes);
+      let levels = paragraph.sliced_mut(levels);
       level_runs.clear();
 
       paragraph.compute_explicit(
@@ -314,13 +462,13 @@ fn cure_reordered(input: &str, options: Options) -> Result<String, Error> {
         original_classes,
         processing_classes,
         levels,
-        &mut level_runs,
+        level_runs,
       )?;
 
       sequences.clear();
-      paragraph.isolating_run_sequences(levels, &level_runs, original_classes, &mut sequences)?;
+      paragraph.isolating_run_sequences(levels, level_runs, original_classes, sequences)?;
 
-      for sequence in &sequences {
+      for sequence in sequences.iter() {
         sequence.resolve_implicit_weak(input, processing_classes);
         sequence.resolve_implicit_neutral(input, processing_classes, levels);
       }
@@ -351,26 +499,103 @@ fn cure_reordered(input: &str, options: Options) -> Result<String, Error> {
     }
   }
 
-  for paragraph in paragraphs {
-    let (revised_levels, runs) =
-      paragraph.visual_runs(&refined_input, &original_classes, &levels)?;
+  Ok(())
+}
 
-    for run in runs {
+fn reorder<F>(scratch: &mut Scratch, mut emit: F) -> Result<(), Error>
+where
+  F: FnMut(usize, char, bool),
+{
+  resolve_levels(scratch)?;
+
+  let Scratch {
+    refined_input,
+    original_classes,
+    paragraphs,
+    levels,
+    revised_levels,
+    visual_runs,
+    ..
+  } = scratch;
+
+  for paragraph in paragraphs.iter() {
+    paragraph.visual_runs(
+      refined_input,
+      original_classes,
+      levels,
+      revised_levels,
+      visual_runs,
+    )?;
+
+    for run in visual_runs.drain(..) {
       let text = &refined_input[run.clone()];
 
       if revised_levels[run.start].is_rtl() {
-        for c in text.chars().rev() {
-          output += cure_char_inner(c as _, options);
+        for (idx, c) in text.char_indices().rev() {
+          emit(run.start + idx, c, true);
         }
       } else {
-        for c in text.chars() {
-          output += cure_char_inner(c as _, options);
+        for (idx, c) in text.char_indices() {
+          emit(run.start + idx, c, false);
         }
       }
     }
   }
 
-  Ok(output)
+  Ok(())
+}
+
+#[cfg_attr(not(feature = "options"), allow(unused_variables))]
+fn is_cured(input: &str, options: Options, overrides: &Overrides) -> bool {
+  if overrides.affects_ascii() {
+    return false;
+  }
+
+  #[cfg(feature = "options")]
+  if options.is(26) {
+    return input
+      .bytes()
+      .all(|byte| is_alphanumeric(byte.into()) && (options.is(0) || !byte.is_ascii_uppercase()));
+  }
+
+  #[cfg(feature = "options")]
+  return is_cured_ascii(input.as_bytes(), options.is(0));
+
+  #[cfg(not(feature = "options"))]
+  is_cured_ascii(input.as_bytes(), false)
+}
+
+fn cure_into_with(
+  input: &str,
+  options: Options,
+  overrides: &Overrides,
+  scratch: &mut Scratch,
+  output: &mut String,
+) -> Result<(), Error> {
+  if is_cured(input, options, overrides) {
+    output.push_str(input);
+
+    return Ok(());
+  }
+
+  output.reserve(input.len());
+
+  #[cfg(feature = "options")]
+  if options.is(1) {
+    for character in input.chars() {
+      if !is_special_rtl(character as _) {
+        *output += cure_char_with(character as _, options, overrides);
+      }
+    }
+
+    return Ok(());
+  }
+
+  first_cure_pass(input, None, Some(overrides), scratch);
+
+  reorder(scratch, |_, c, _| {
+    *output += cure_char_inner(c as _, options, overrides).0;
+  })
 }
 
 /// Cures a string with the specified [`Options`].
@@ -381,33 +606,254 @@ fn cure_reordered(input: &str, options: Options) -> Result<String, Error> {
 ///
 /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
 pub fn cure(input: &str, options: Options) -> Result<CuredString, Error> {
-  Ok(CuredString {
-    string: {
-      #[cfg(feature = "options")]
-      if options.is(1) {
-        input
-          .chars()
-          .filter(|&character| !is_special_rtl(character as _))
-          .fold(
-            String::with_capacity(input.len()),
-            |mut output, character| {
-              output += cure_char(character, options);
-              output
-            },
-          )
-      } else {
-        cure_reordered(input, options)?
-      }
+  cure_with(input, options, &overrides::EMPTY)
+}
 
-      #[cfg(not(feature = "options"))]
-      cure_reordered(input, options)?
+fn cure_with(input: &str, options: Options, overrides: &Overrides) -> Result<CuredString, Error> {
+  let mut output = String::new();
+
+  cure_into_with(
+    input,
+    options,
+    overrides,
+    &mut Scratch::default(),
+    &mut output,
+  )?;
+
+  Ok(CuredString::new(output, options))
+}
+
+/// Cures a string with the specified [`Options`], borrowing the input instead of copying it if it's already cured.
+///
+/// Most strings are plain ASCII text that wouldn't be changed by curing at all. This function checks for that first (several bytes at a time) and only cures the input otherwise. The output is a [`CuredStr`] with the same comparison methods as [`CuredString`], use [`into_owned`][CuredStr::into_owned] to turn it into one.
+///
+/// # Errors
+///
+/// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// use std::borrow::Cow;
+///
+/// let cured = decancer::cure_cow("hello, world!", decancer::Options::default()).unwrap();
+///
+/// assert!(cured.contains("world"));
+/// assert!(matches!(Cow::from(cured), Cow::Borrowed("hello, world!")));
+///
+/// let cured = decancer::cure_cow("Ｈello, world!", decancer::Options::default()).unwrap();
+///
+/// assert_eq!(cured, "hello, world!");
+/// assert!(matches!(Cow::from(cured), Cow::Owned(_)));
+/// ```
+pub fn cure_cow(input: &str, options: Options) -> Result<CuredStr<'_>, Error> {
+  if is_cured(input, options, &overrides::EMPTY) {
+    return Ok(CuredStr::new(input, options));
+  }
+
+  let mut output = String::new();
+
+  cure_into_with(
+    input,
+    options,
+    &overrides::EMPTY,
+    &mut Scratch::default(),
+    &mut output,
+  )?;
+
+  Ok(CuredStr::new(output, options))
+}
+
+/// Cures a string with the specified [`Options`] while keeping track of which part of the input produced each part of the output.
+///
+/// This is slower than [`cure`][cure()], use it only when you need to map matches in the cured string back to the original input (e.g: for highlighting or redacting what the user actually wrote). See [`MappedCuredString::original_ranges`] for more information.
+///
+/// # Errors
+///
+/// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// let cured = decancer::cure_mapped("vＥⓡ𝔂 𝔽𝕌Ňℕｙ ţ乇𝕏𝓣", decancer::Options::default()).unwrap();
+/// let mat = cured.find("funny").next().unwrap();
+///
+/// assert_eq!(&cured.original()[cured.original_ranges(mat)[0].clone()], "𝔽𝕌Ňℕｙ");
+/// ```
+pub fn cure_mapped(input: &str, options: Options) -> Result<MappedCuredString<'_>, Error> {
+  cure_mapped_with(input, options, &overrides::EMPTY)
+}
+
+fn cure_mapped_with<'a>(
+  input: &'a str,
+  options: Options,
+  overrides: &Overrides,
+) -> Result<MappedCuredString<'a>, Error> {
+  let mut mapped = MappedCuredString::new(
+    input,
+    CuredString::new(String::with_capacity(input.len()), options),
+  );
+
+  #[cfg(feature = "options")]
+  if options.is(1) {
+    for (idx, character) in input.char_indices() {
+      mapped.push(
+        if is_special_rtl(character as _) {
+          Translation::None
+        } else {
+          cure_char_with(character as _, options, overrides)
+        },
+        idx..idx + character.len_utf8(),
+      );
     }
-    .into(),
-    #[cfg(all(feature = "leetspeak", feature = "options"))]
-    disable_leetspeak: options.is(2),
-    #[cfg(all(feature = "leetspeak", feature = "options"))]
-    disable_alphabetical_leetspeak: options.is(3),
-  })
+
+    return Ok(mapped);
+  }
+
+  let mut offsets = Vec::with_capacity(input.len());
+  let mut scratch = Scratch::default();
+
+  first_cure_pass(input, Some(&mut offsets), Some(overrides), &mut scratch);
+
+  // characters removed in the first pass are attributed to the character before them.
+  reorder(&mut scratch, |idx, c, _| {
+    let start = if idx == 0 { 0 } else { offsets[idx] };
+    let end = offsets
+      .get(idx + c.len_utf8())
+      .copied()
+      .unwrap_or(input.len());
+
+    mapped.push(cure_char_inner(c as _, options, overrides).0, start..end);
+  })?;
+
+  Ok(mapped)
+}
+
+/// Cures a string with the specified [`Options`] while recording how every character of the input was cured.
+///
+/// This is slower than [`cure`][cure()], use it only when you need to show why a string was cured the way it was (e.g: in a moderation audit log). See [`CureStep`] for more information.
+///
+/// # Errors
+///
+/// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// use decancer::CureSource;
+///
+/// let explanation = decancer::cure_explained("Ｈi\u{7}!", decancer::Options::default()).unwrap();
+///
+/// assert_eq!(explanation.cured, "hi!");
+///
+/// let step = &explanation.steps[0];
+///
+/// assert_eq!(step.character, 'Ｈ');
+/// assert_eq!(step.original, 0..3);
+/// assert_eq!(step.cured, 0..1);
+/// assert_eq!(step.source, CureSource::MainTable);
+/// assert_eq!(explanation.steps[1].source, CureSource::Ascii);
+/// assert_eq!(explanation.steps[2].source, CureSource::Removed);
+/// ```
+pub fn cure_explained(input: &str, options: Options) -> Result<Explanation, Error> {
+  cure_explained_with(input, options, &overrides::EMPTY)
+}
+
+fn cure_explained_with(
+  input: &str,
+  options: Options,
+  overrides: &Overrides,
+) -> Result<Explanation, Error> {
+  let mut output = String::with_capacity(input.len());
+  let mut steps = Vec::with_capacity(input.len());
+
+  let mut push_step = |character: char,
+                       original: Range<usize>,
+                       (translation, source): (Translation, CureSource),
+                       reversed: bool| {
+    let start = output.len();
+
+    output += translation.clone();
+
+    steps.push(CureStep {
+      character,
+      original,
+      cured: start..output.len(),
+      translation,
+      source,
+      reversed,
+    });
+  };
+
+  #[cfg(feature = "options")]
+  if options.is(1) {
+    for (idx, character) in input.char_indices() {
+      push_step(
+        character,
+        idx..idx + character.len_utf8(),
+        if is_special_rtl(character as _) {
+          (Translation::None, CureSource::BidiControl)
+        } else {
+          cure_char_traced(character as _, options, overrides)
+        },
+        false,
+      );
+    }
+
+    return Ok(Explanation::new(
+      CuredString::new(output, options),
+      steps,
+      input,
+    ));
+  }
+
+  let mut offsets = Vec::with_capacity(input.len());
+  let mut scratch = Scratch::default();
+
+  first_cure_pass(input, Some(&mut offsets), Some(overrides), &mut scratch);
+
+  reorder(&mut scratch, |idx, c, reversed| {
+    let start = offsets[idx];
+    let character = input[start..].chars().next().unwrap();
+    let (translation, source) = cure_char_traced(c as _, options, overrides);
+
+    push_step(
+      character,
+      start..start + character.len_utf8(),
+      (
+        translation,
+        if is_special_rtl(c as _) {
+          CureSource::BidiControl
+        } else {
+          source
+        },
+      ),
+      reversed,
+    );
+  })?;
+
+  Ok(Explanation::new(
+    CuredString::new(output, options),
+    steps,
+    input,
+  ))
+}
+
+/// Analyzes how disguised a string is with the specified [`Options`].
+///
+/// This is a shorthand for calling [`Explanation::report`] on the result of [`cure_explained`][cure_explained()]. See [`Report`] for more information.
+///
+/// # Errors
+///
+/// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+pub fn analyze(input: &str, options: Options) -> Result<Report, Error> {
+  cure_explained(input, options).map(|explanation| explanation.report())
 }
 
 /// Cures a string with decancer's default options.
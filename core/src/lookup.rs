@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{
+  codepoints::{CASE_SENSITIVE_CODEPOINTS_OFFSET, Codepoint},
+  util::Binary,
+};
+
+// generated by build.rs, see generate_lookup_table() for the layout.
+const LOOKUP: Binary = Binary::new(include_bytes!(concat!(env!("OUT_DIR"), "/lookup.bin")));
+
+const PAGE_COUNT: u32 = 0x1100;
+const PAGES_OFFSET: u32 = PAGE_COUNT * 4;
+
+// just like Codepoint::binary_search, only the first end + 1 entries of the table are searched.
+pub const fn search(code: u32, offset: i32, end: i32) -> Option<Codepoint> {
+  if code >= PAGE_COUNT << 8 {
+    return None;
+  }
+
+  let table = if offset == CASE_SENSITIVE_CODEPOINTS_OFFSET as i32 {
+    PAGE_COUNT * 2
+  } else {
+    0
+  };
+
+  let page = LOOKUP.u16_at((table + ((code >> 8) * 2)) as _) as u32;
+  let idx = LOOKUP.u16_at((PAGES_OFFSET + (page * 512) + ((code & 0xff) * 2)) as _) as i32;
+
+  if idx == 0 || idx - 1 > end {
+    None
+  } else {
+    Some(Codepoint::at(offset + ((idx - 1) * 6)))
+  }
+}
@@ -0,0 +1,248 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{CuredString, Translation, util::merge_ranges};
+use std::{
+  fmt::{self, Debug, Formatter},
+  ops::{Deref, Range},
+};
+
+#[derive(Clone)]
+struct Segment {
+  cured: Range<usize>,
+  original: Range<usize>,
+}
+
+impl Segment {
+  const fn is_adjacent(&self, original: &Range<usize>) -> bool {
+    self.original.end == original.start || original.end == self.original.start
+  }
+
+  fn absorb(&mut self, original: &Range<usize>) {
+    self.original = self.original.start.min(original.start)..self.original.end.max(original.end);
+  }
+}
+
+/// A [`CuredString`] that keeps track of which part of the original input produced each part of it.
+///
+/// Every byte of the original input belongs to exactly one part of the cured string. Characters that are removed from the output (e.g: diacritics or control characters) are attributed to a neighbouring character whenever possible.
+///
+/// This struct dereferences to a [`CuredString`], so every comparison method is available as well.
+#[derive(Clone)]
+pub struct MappedCuredString<'a> {
+  original: &'a str,
+  cured: CuredString,
+  segments: Vec<Segment>,
+}
+
+impl<'a> MappedCuredString<'a> {
+  pub(super) fn new(original: &'a str, cured: CuredString) -> Self {
+    Self {
+      original,
+      cured,
+      segments: Vec::with_capacity(original.len()),
+    }
+  }
+
+  pub(super) fn push(&mut self, translation: Translation, original: Range<usize>) {
+    let string = self.cured.string.to_mut();
+    let start = string.len();
+
+    *string += translation;
+
+    let mut segment = Segment {
+      cured: start..string.len(),
+      original,
+    };
+
+    if let Some(last) = self.segments.last_mut()
+      && (last.cured.is_empty() || segment.cured.is_empty())
+      && last.is_adjacent(&segment.original)
+    {
+      if segment.cured.is_empty() {
+        last.absorb(&segment.original);
+
+        return;
+      }
+
+      segment.absorb(&last.original);
+      self.segments.pop();
+    }
+
+    self.segments.push(segment);
+  }
+
+  /// Returns the original input that this string was cured from.
+  #[must_use]
+  pub const fn original(&self) -> &'a str {
+    self.original
+  }
+
+  /// Translates a range in this cured string to the ranges in the original input that produced it.
+  ///
+  /// The returned ranges are sorted and never overlap. More than one range may be returned since [bidirectional reordering](https://en.wikipedia.org/wiki/Bidirectional_text) can place characters that are far apart in the original input next to each other in the cured string.
+  ///
+  /// ```rust
+  /// let cured = decancer::cure_mapped("wow ＨＥＬＬＯ!", decancer::Options::default()).unwrap();
+  /// let mat = cured.find("hello").next().unwrap();
+  ///
+  /// assert_eq!(mat, 4..9);
+  /// assert_eq!(cured.original_ranges(mat), [4..19]);
+  /// ```
+  #[must_use]
+  pub fn original_ranges(&self, cured: Range<usize>) -> Vec<Range<usize>> {
+    if cured.is_empty() {
+      return vec![];
+    }
+
+    let start = self
+      .segments
+      .partition_point(|segment| segment.cured.end <= cured.start);
+
+    let mut ranges: Vec<_> = self.segments[start..]
+      .iter()
+      .take_while(|segment| segment.cured.start < cured.end)
+      .map(|segment| segment.original.clone())
+      .collect();
+
+    merge_ranges(&mut ranges);
+    ranges
+  }
+
+  fn rewrite_original<I, F>(&self, matches: I, mut with: F) -> String
+  where
+    I: IntoIterator<Item = Range<usize>>,
+    F: FnMut(usize, &mut String),
+  {
+    let mut ranges: Vec<_> = matches
+      .into_iter()
+      .flat_map(|mat| self.original_ranges(mat))
+      .collect();
+
+    merge_ranges(&mut ranges);
+
+    let mut visible_starts: Vec<_> = self
+      .segments
+      .iter()
+      .filter(|segment| !segment.cured.is_empty())
+      .map(|segment| segment.original.start)
+      .collect();
+
+    visible_starts.sort_unstable();
+
+    let mut output = String::with_capacity(self.original.len());
+    let mut last_end = 0;
+
+    for range in ranges {
+      let visible_count = visible_starts.partition_point(|&start| start < range.end)
+        - visible_starts.partition_point(|&start| start < range.start);
+
+      output.push_str(&self.original[last_end..range.start]);
+      with(visible_count, &mut output);
+
+      last_end = range.end;
+    }
+
+    output.push_str(&self.original[last_end..]);
+    output
+  }
+
+  /// Returns a copy of the original input where every match of a string is censored with a repetition of a character.
+  ///
+  /// Unlike [`CuredString::censor`], everything outside of the matches is left exactly as the user wrote it. Each censored part is replaced with one character for every character of it that is still visible in the cured string, so removed characters like diacritics don't lengthen the censor.
+  ///
+  /// If you plan on using this method with an array of strings, use [`censor_multiple_original`][MappedCuredString::censor_multiple_original].
+  ///
+  /// This comparison is case-insensitive.
+  ///
+  /// ```rust
+  /// let cured = decancer::cure_mapped("Wow, ＨＥＬＬＯ there 👋", decancer::Options::default()).unwrap();
+  ///
+  /// assert_eq!(cured.censor_original("hello", '*'), "Wow, ***** there 👋");
+  /// ```
+  #[must_use]
+  pub fn censor_original(&self, other: &str, with: char) -> String {
+    self.rewrite_original(self.find(other), |visible_count, output| {
+      output.extend((0..visible_count).map(|_| with));
+    })
+  }
+
+  /// Returns a copy of the original input where every match from an array of strings is censored with a repetition of a character.
+  ///
+  /// This comparison is case-insensitive.
+  ///
+  /// ```rust
+  /// let cured = decancer::cure_mapped("ＨＥＬＬＯ, Oh Yeah!", decancer::Options::default()).unwrap();
+  ///
+  /// assert_eq!(cured.censor_multiple_original(["hello", "oh yeah"], '*'), "*****, *******!");
+  /// ```
+  #[must_use]
+  pub fn censor_multiple_original<S, O>(&self, other: O, with: char) -> String
+  where
+    S: AsRef<str>,
+    O: IntoIterator<Item = S>,
+  {
+    self.rewrite_original(self.find_multiple(other), |visible_count, output| {
+      output.extend((0..visible_count).map(|_| with));
+    })
+  }
+
+  /// Returns a copy of the original input where every match of a string is replaced with another string.
+  ///
+  /// Unlike [`CuredString::replace`], everything outside of the matches is left exactly as the user wrote it. If a match is split into several parts of the original input by [bidirectional reordering](https://en.wikipedia.org/wiki/Bidirectional_text), each part is replaced.
+  ///
+  /// If you plan on using this method with an array of strings, use [`replace_multiple_original`][MappedCuredString::replace_multiple_original].
+  ///
+  /// This comparison is case-insensitive.
+  ///
+  /// ```rust
+  /// let cured = decancer::cure_mapped("Wow, ＨＥＬＬＯ there!", decancer::Options::default()).unwrap();
+  ///
+  /// assert_eq!(cured.replace_original("hello", "Hi"), "Wow, Hi there!");
+  /// ```
+  #[must_use]
+  pub fn replace_original(&self, other: &str, with: &str) -> String {
+    self.rewrite_original(self.find(other), |_, output| output.push_str(with))
+  }
+
+  /// Returns a copy of the original input where every match from an array of strings is replaced with another string.
+  ///
+  /// This comparison is case-insensitive.
+  ///
+  /// ```rust
+  /// let cured = decancer::cure_mapped("ＨＥＬＬＯ, Oh Yeah!", decancer::Options::default()).unwrap();
+  ///
+  /// assert_eq!(cured.replace_multiple_original(["hello", "oh yeah"], "no"), "no, no!");
+  /// ```
+  #[must_use]
+  pub fn replace_multiple_original<S, O>(&self, other: O, with: &str) -> String
+  where
+    S: AsRef<str>,
+    O: IntoIterator<Item = S>,
+  {
+    self.rewrite_original(self.find_multiple(other), |_, output| {
+      output.push_str(with);
+    })
+  }
+}
+
+impl Deref for MappedCuredString<'_> {
+  type Target = CuredString;
+
+  fn deref(&self) -> &Self::Target {
+    &self.cured
+  }
+}
+
+impl From<MappedCuredString<'_>> for CuredString {
+  /// Discards the offset map and returns the cured string.
+  fn from(mapped: MappedCuredString<'_>) -> Self {
+    mapped.cured
+  }
+}
+
+impl Debug for MappedCuredString<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    Debug::fmt(&self.cured, f)
+  }
+}
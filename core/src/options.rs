@@ -2,7 +2,17 @@
 // SPDX-FileCopyrightText: 2021-2026 null8626
 
 use super::{Translation, codepoints::Codepoint};
-use std::cmp::Ordering;
+#[cfg(all(feature = "options", feature = "serde"))]
+use serde::{
+  Deserialize, Deserializer, Serialize, Serializer,
+  de::{self, MapAccess, SeqAccess, Visitor},
+};
+#[cfg(feature = "options")]
+use std::{
+  error::Error,
+  fmt::{self, Display, Formatter},
+  str::FromStr,
+};
 
 /// A configuration struct where you can customize decancer's behavior.
 ///
@@ -16,18 +26,41 @@ use std::cmp::Ordering;
 /// // by default, all options are disabled
 /// let _options = Options::default();
 /// ```
+///
+/// With the `serde` feature enabled, this struct is serialized as a list of the names of every enabled option (e.g: `["retain_greek", "ascii_only"]`). It can be deserialized from such a list, a single name, or a map of names to booleans (e.g: `{ "retain_greek": true }`) where later entries take precedence. The names `all` and `pure_homoglyph` can also be used for [`ALL`][Options::ALL] and [`PURE_HOMOGLYPH`][Options::PURE_HOMOGLYPH] respectively. Unknown names and duplicate map keys are rejected.
+///
+/// Formats that are not human-readable (e.g: bincode or postcard) are often not self-describing, thus only the list form is supported in them.
 #[derive(Copy, Clone, Default, Eq, Hash, PartialEq)]
 pub struct Options(pub(super) u32);
 
 macro_rules! options {
   ($(
-    $(#[$extra_meta:meta])*
-    $idx:literal: $name:ident
+    $(#[doc = $doc:literal])*
+    $(#[cfg($cfg:meta)])?
+    $idx:literal: $name:ident => $description:literal
   ),*) => {
+    // the name of every option that is supported by this build in the order of their indices, followed by the name of every preset.
+    #[cfg(all(feature = "options", feature = "serde"))]
+    const NAMES: &'static [&'static str] = &[$(
+      $(#[cfg($cfg)])?
+      stringify!($name),
+    )* "all", "pure_homoglyph"];
+
+    #[cfg(feature = "options")]
+    const FLAGS: &'static [OptionFlag] = &[$(
+      $(#[cfg($cfg)])?
+      OptionFlag {
+        name: stringify!($name),
+        description: $description,
+        index: $idx,
+      },
+    )*];
+
     $(
-      $(#[$extra_meta])*
+      $(#[doc = $doc])*
       #[must_use]
       #[cfg(feature = "options")]
+      $(#[cfg($cfg)])?
       pub const fn $name(self) -> Self {
         Self(self.0 | (1 << $idx))
       }
@@ -37,6 +70,8 @@ macro_rules! options {
 
 impl Options {
   /// A configuration where every option is enabled.
+  ///
+  /// Options that are not supported by this build (e.g: [`disable_leetspeak`][Options::disable_leetspeak] without the `leetspeak` feature) are still enabled, but they have no effect and they are not [listed][Options::flags].
   #[cfg(feature = "options")]
   pub const ALL: Self = Self(0x7ff_ffff);
 
@@ -64,95 +99,126 @@ impl Options {
     ///   _ => unreachable!("cure_char 🆐 should always return a Translation::String")
     /// }
     /// ```
-    0: retain_capitalization,
+    0: retain_capitalization => "Prevents decancer from changing all characters to lowercase.",
 
     /// Prevents decancer from applying the [Unicode Bidirectional Algorithm](https://en.wikipedia.org/wiki/Bidirectional_text). Use this **only** when you don't expect any right-to-left characters. Enabling this option has no effect if it's called on [`cure_char`][super::cure_char()].
     ///
     /// **NOTE:** This speeds up the function call, but **can break [right-to-left characters](https://en.wikipedia.org/wiki/Bidirectional_text)**. It's highly recommended to also use [`retain_arabic`][Options::retain_arabic] and [`retain_hebrew`][Options::retain_hebrew].
-    1: disable_bidi,
+    1: disable_bidi => "Prevents decancer from applying the Unicode Bidirectional Algorithm.",
 
     /// Prevents decancer from applying leetspeak comparisons in [`CuredString`][super::CuredString]'s comparison methods.
     #[cfg(feature = "leetspeak")]
-    2: disable_leetspeak,
+    2: disable_leetspeak => "Prevents decancer from applying leetspeak comparisons in CuredString's comparison methods.",
 
     /// Prevents decancer from applying alphabetical leetspeak comparisons in [`CuredString`][super::CuredString]'s comparison methods.
     #[cfg(feature = "leetspeak")]
-    3: disable_alphabetical_leetspeak,
+    3: disable_alphabetical_leetspeak => "Prevents decancer from applying alphabetical leetspeak comparisons in CuredString's comparison methods.",
 
     /// Prevents decancer from curing characters *with* diacritics or accents.
     ///
     /// **NOTE:** Decancer can still cure standalone diacritic characters, which is used in [Zalgo texts](https://en.wikipedia.org/wiki/Zalgo_text).
-    4: retain_diacritics,
+    4: retain_diacritics => "Prevents decancer from curing characters with diacritics or accents.",
 
     /// Prevents decancer from curing all greek characters.
-    5: retain_greek,
+    5: retain_greek => "Prevents decancer from curing all greek characters.",
 
     /// Prevents decancer from curing all cyrillic characters.
-    6: retain_cyrillic,
+    6: retain_cyrillic => "Prevents decancer from curing all cyrillic characters.",
 
     /// Prevents decancer from curing all hebrew characters.
-    7: retain_hebrew,
+    7: retain_hebrew => "Prevents decancer from curing all hebrew characters.",
 
     /// Prevents decancer from curing all arabic characters.
-    8: retain_arabic,
+    8: retain_arabic => "Prevents decancer from curing all arabic characters.",
 
     /// Prevents decancer from curing all devanagari characters.
-    9: retain_devanagari,
+    9: retain_devanagari => "Prevents decancer from curing all devanagari characters.",
 
     /// Prevents decancer from curing all bengali characters.
-    10: retain_bengali,
+    10: retain_bengali => "Prevents decancer from curing all bengali characters.",
 
     /// Prevents decancer from curing all armenian characters.
-    11: retain_armenian,
+    11: retain_armenian => "Prevents decancer from curing all armenian characters.",
 
     /// Prevents decancer from curing all gujarati characters.
-    12: retain_gujarati,
+    12: retain_gujarati => "Prevents decancer from curing all gujarati characters.",
 
     /// Prevents decancer from curing all tamil characters.
-    13: retain_tamil,
+    13: retain_tamil => "Prevents decancer from curing all tamil characters.",
 
     /// Prevents decancer from curing all thai characters.
-    14: retain_thai,
+    14: retain_thai => "Prevents decancer from curing all thai characters.",
 
     /// Prevents decancer from curing all lao characters.
-    15: retain_lao,
+    15: retain_lao => "Prevents decancer from curing all lao characters.",
 
     /// Prevents decancer from curing all burmese characters.
-    16: retain_burmese,
+    16: retain_burmese => "Prevents decancer from curing all burmese characters.",
 
     /// Prevents decancer from curing all khmer characters.
-    17: retain_khmer,
+    17: retain_khmer => "Prevents decancer from curing all khmer characters.",
 
     /// Prevents decancer from curing all mongolian characters.
-    18: retain_mongolian,
+    18: retain_mongolian => "Prevents decancer from curing all mongolian characters.",
 
     /// Prevents decancer from curing all chinese characters.
-    19: retain_chinese,
+    19: retain_chinese => "Prevents decancer from curing all chinese characters.",
 
     /// Prevents decancer from curing all katakana and hiragana characters.
     ///
     /// **NOTE:** To also prevent decancer from curing kanji characters, use [`retain_chinese`][Options::retain_chinese].
-    20: retain_japanese,
+    20: retain_japanese => "Prevents decancer from curing all katakana and hiragana characters.",
 
     /// Prevents decancer from curing all korean characters.
-    21: retain_korean,
+    21: retain_korean => "Prevents decancer from curing all korean characters.",
 
     /// Prevents decancer from curing all braille characters.
-    22: retain_braille,
+    22: retain_braille => "Prevents decancer from curing all braille characters.",
 
     /// Prevents decancer from curing all emojis.
-    23: retain_emojis,
+    23: retain_emojis => "Prevents decancer from curing all emojis.",
 
     /// Prevents decancer from curing all turkish characters.
     ///
     /// **NOTE:** To also prevent decancer from curing [the uppercase dotted i character](https://en.wikipedia.org/wiki/İ) (`İ`), use [`retain_capitalization`][Options::retain_capitalization].
-    24: retain_turkish,
+    24: retain_turkish => "Prevents decancer from curing all turkish characters.",
 
     /// Removes all non-ASCII characters from the result.
-    25: ascii_only,
+    25: ascii_only => "Removes all non-ASCII characters from the result.",
 
     /// Removes all non-alphanumeric characters from the result.
-    26: alphanumeric_only
+    26: alphanumeric_only => "Removes all non-alphanumeric characters from the result."
+  }
+
+  /// Returns an iterator of every option in the order of their indices, along with their names and descriptions. This can be used to list every supported option in user interfaces.
+  ///
+  /// ```rust
+  /// use decancer::Options;
+  ///
+  /// let flag = Options::flags().find(|flag| flag.name == "retain_greek").unwrap();
+  ///
+  /// assert_eq!(flag.description, "Prevents decancer from curing all greek characters.");
+  /// assert!(flag.is_enabled(Options::default().retain_greek()));
+  /// assert!(!flag.is_enabled(Options::default()));
+  /// ```
+  #[must_use]
+  #[cfg(feature = "options")]
+  pub fn flags() -> impl ExactSizeIterator<Item = OptionFlag> + Clone {
+    Self::FLAGS.iter().copied()
+  }
+
+  // returns the bits of an option or a preset from its name.
+  #[cfg(feature = "options")]
+  fn flag(name: &str) -> Option<u32> {
+    match name {
+      "all" => Some(Self::ALL.0),
+
+      "pure_homoglyph" => Some(Self::PURE_HOMOGLYPH.0),
+
+      _ => Self::flags()
+        .find(|flag| flag.name == name)
+        .map(|flag| flag.options().0),
+    }
   }
 
   #[cfg(feature = "options")]
@@ -169,36 +235,129 @@ impl Options {
       || locale > 4 && self.is(locale)
   }
 
-  pub(super) fn translate(self, code: u32, offset: i32, mut end: i32) -> Option<Translation> {
-    let mut start = 0;
+  pub(super) fn translate(self, code: u32, offset: i32, end: i32) -> Option<Translation> {
+    let codepoint = Codepoint::search(code, offset, end)?;
 
-    while start <= end {
-      let mid = start.midpoint(end);
-      let codepoint = Codepoint::at(offset + (mid * 6));
-      #[cfg(feature = "options")]
-      let ord = codepoint.matches(code, self)?;
+    #[cfg(feature = "options")]
+    if codepoint.is_retained(self) {
+      return None;
+    }
 
-      #[cfg(not(feature = "options"))]
-      let ord = codepoint.matches(code)?;
+    Some(codepoint.translation(
+      code,
+      #[cfg(all(feature = "leetspeak", feature = "options"))]
+      self.is(2),
+      #[cfg(all(feature = "leetspeak", feature = "options"))]
+      self.is(3),
+    ))
+  }
 
-      match ord {
-        Ordering::Equal => {
-          return Some(codepoint.translation(
-            code,
-            #[cfg(all(feature = "leetspeak", feature = "options"))]
-            self.is(2),
-            #[cfg(all(feature = "leetspeak", feature = "options"))]
-            self.is(3),
-          ));
-        },
+  // returns true if the codepoint is known by decancer but one of the options prevents it from being cured.
+  #[cfg(feature = "options")]
+  pub(super) fn retains(self, code: u32, offset: i32, end: i32) -> bool {
+    Codepoint::search(code, offset, end).is_some_and(|codepoint| codepoint.is_retained(self))
+  }
+}
+
+/// Metadata of a single option in [`Options`]. See [`Options::flags`] for more information.
+#[cfg(feature = "options")]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionFlag {
+  /// The name of this option, which is the same as the name of its method in [`Options`].
+  pub name: &'static str,
 
-        Ordering::Greater => start = mid + 1,
+  /// A short description of this option.
+  pub description: &'static str,
+
+  /// The index of this option's bit, which is used by decancer's bindings to represent [`Options`] as a number.
+  pub index: u8,
+}
+
+#[cfg(feature = "options")]
+impl OptionFlag {
+  /// Returns an [`Options`] where only this option is enabled.
+  #[must_use]
+  pub const fn options(self) -> Options {
+    Options(1 << self.index)
+  }
+
+  /// Returns whether this option is enabled in the specified [`Options`].
+  #[must_use]
+  pub const fn is_enabled(self, options: Options) -> bool {
+    options.is(self.index)
+  }
+}
+
+/// An error returned when parsing an [`Options`] from a string that contains an unknown option name.
+#[cfg(feature = "options")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ParseOptionsError(String);
+
+#[cfg(feature = "options")]
+impl ParseOptionsError {
+  /// Returns the unknown option name.
+  #[must_use]
+  pub fn name(&self) -> &str {
+    &self.0
+  }
+}
+
+#[cfg(feature = "options")]
+impl Display for ParseOptionsError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "unknown option `{}`", self.0)
+  }
+}
+
+#[cfg(feature = "options")]
+impl Error for ParseOptionsError {}
+
+#[cfg(feature = "options")]
+impl FromStr for Options {
+  type Err = ParseOptionsError;
+
+  /// Parses a comma-separated list of option names (e.g: `retain_capitalization,disable_bidi`). Whitespace around every name is ignored, and the names `all` and `pure_homoglyph` can also be used for [`ALL`][Options::ALL] and [`PURE_HOMOGLYPH`][Options::PURE_HOMOGLYPH] respectively.
+  ///
+  /// ```rust
+  /// use decancer::Options;
+  ///
+  /// let options: Options = "retain_capitalization, disable_bidi".parse().unwrap();
+  ///
+  /// assert!(options == Options::default().retain_capitalization().disable_bidi());
+  /// assert_eq!(options.to_string(), "retain_capitalization,disable_bidi");
+  ///
+  /// let err = "retain_klingon".parse::<Options>().err().unwrap();
+  ///
+  /// assert_eq!(err.name(), "retain_klingon");
+  /// ```
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    s.split(',')
+      .map(str::trim)
+      .filter(|name| !name.is_empty())
+      .try_fold(Self::default(), |options, name| {
+        Self::flag(name)
+          .map(|flag| Self(options.0 | flag))
+          .ok_or_else(|| ParseOptionsError(name.to_owned()))
+      })
+  }
+}
 
-        Ordering::Less => end = mid - 1,
+#[cfg(feature = "options")]
+impl Display for Options {
+  /// Formats the names of every enabled option as a comma-separated list, which can be parsed back with [`FromStr`].
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    for (idx, flag) in Self::flags()
+      .filter(|flag| flag.is_enabled(*self))
+      .enumerate()
+    {
+      if idx > 0 {
+        f.write_str(",")?;
       }
+
+      f.write_str(flag.name)?;
     }
 
-    None
+    Ok(())
   }
 }
 
@@ -209,3 +368,143 @@ impl From<u32> for Options {
     Self(value)
   }
 }
+
+#[cfg(all(feature = "options", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "options", feature = "serde"))))]
+impl Serialize for Options {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_seq(
+      Self::flags()
+        .filter(|flag| flag.is_enabled(*self))
+        .map(|flag| flag.name),
+    )
+  }
+}
+
+// the bits of a single option or preset, which is deserialized through a visitor so that transient strings are supported as well.
+#[cfg(all(feature = "options", feature = "serde"))]
+struct Bits(u32);
+
+#[cfg(all(feature = "options", feature = "serde"))]
+impl Bits {
+  // returns the name of the option or preset that was deserialized into these bits.
+  fn name(&self) -> &'static str {
+    if self.0 == Options::ALL.0 {
+      "all"
+    } else if self.0 == Options::PURE_HOMOGLYPH.0 {
+      "pure_homoglyph"
+    } else {
+      Options::flags()
+        .find(|flag| flag.options().0 == self.0)
+        .map_or("", |flag| flag.name)
+    }
+  }
+}
+
+#[cfg(all(feature = "options", feature = "serde"))]
+impl<'de> Deserialize<'de> for Bits {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_str(BitsVisitor)
+  }
+}
+
+// unlike OptionsVisitor, this only accepts a single name. otherwise, lists and maps could be nested inside each other.
+#[cfg(all(feature = "options", feature = "serde"))]
+struct BitsVisitor;
+
+#[cfg(all(feature = "options", feature = "serde"))]
+impl Visitor<'_> for BitsVisitor {
+  type Value = Bits;
+
+  fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str("an option name")
+  }
+
+  fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+  where
+    E: de::Error,
+  {
+    Options::flag(name)
+      .map(Bits)
+      .ok_or_else(|| E::unknown_variant(name, Options::NAMES))
+  }
+}
+
+#[cfg(all(feature = "options", feature = "serde"))]
+struct OptionsVisitor;
+
+#[cfg(all(feature = "options", feature = "serde"))]
+impl<'de> Visitor<'de> for OptionsVisitor {
+  type Value = Bits;
+
+  fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str("an option name, a list of option names or a map of option names to booleans")
+  }
+
+  fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+  where
+    E: de::Error,
+  {
+    BitsVisitor.visit_str(name)
+  }
+
+  fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+  where
+    A: SeqAccess<'de>,
+  {
+    let mut bits = 0;
+
+    while let Some(Bits(flag)) = seq.next_element()? {
+      bits |= flag;
+    }
+
+    Ok(Bits(bits))
+  }
+
+  fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+  where
+    A: MapAccess<'de>,
+  {
+    let mut bits = 0;
+    let mut seen = vec![];
+
+    while let Some((key, enabled)) = map.next_entry::<Bits, bool>()? {
+      if seen.contains(&key.0) {
+        return Err(de::Error::duplicate_field(key.name()));
+      }
+
+      seen.push(key.0);
+
+      if enabled {
+        bits |= key.0;
+      } else {
+        bits &= !key.0;
+      }
+    }
+
+    Ok(Bits(bits))
+  }
+}
+
+#[cfg(all(feature = "options", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "options", feature = "serde"))))]
+impl<'de> Deserialize<'de> for Options {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    // non-self-describing formats don't support deserialize_any.
+    if deserializer.is_human_readable() {
+      deserializer.deserialize_any(OptionsVisitor)
+    } else {
+      deserializer.deserialize_seq(OptionsVisitor)
+    }
+    .map(|Bits(bits)| Self(bits))
+  }
+}
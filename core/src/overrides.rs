@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+#[cfg(feature = "options")]
+use super::codepoints::{
+  CASE_SENSITIVE_CODEPOINTS_COUNT, CASE_SENSITIVE_CODEPOINTS_OFFSET, CODEPOINTS_COUNT,
+};
+use super::{CuredString, Error, Explanation, MappedCuredString, Options, Translation};
+use std::{
+  borrow::Cow,
+  ops::{Bound, RangeBounds},
+};
+
+pub static EMPTY: Overrides = Overrides::new();
+
+#[derive(Clone, Debug)]
+struct Entry {
+  start: u32,
+  end: u32,
+  translation: Translation,
+}
+
+/// A custom table of translations that is consulted before decancer's own.
+///
+/// This is useful for curing newly discovered confusables before they are added to decancer. Every translation applies to an inclusive range of codepoints, and newer translations take precedence over older ones they overlap with.
+///
+/// Just like decancer's own translations, the translations in this table are stored in lowercase. The [`Options`] passed to this table's methods still apply to them. Codepoints that decancer would not cure because of a `retain_*` option are left untouched, [`retain_capitalization`][Options::retain_capitalization] turns the translations of uppercase characters to uppercase, and [`ascii_only`][Options::ascii_only] and [`alphanumeric_only`][Options::alphanumeric_only] still remove translations that don't satisfy them.
+///
+/// This table is also consulted for codepoints that decancer would otherwise remove or turn into a space, like private use characters, control characters and whitespace.
+///
+/// ```rust
+/// use decancer::{Options, Overrides, Translation};
+///
+/// let overrides = Overrides::new()
+///   .character('ꙮ'..='ꙮ', 'o')
+///   .string('㎧'..='㎧', "m/s")
+///   .remove('♡'..='♥');
+///
+/// assert_eq!(overrides.cure_char('ꙮ', Options::default()), Translation::Character('o'));
+/// assert_eq!(overrides.cure_char('♥', Options::default()), Translation::None);
+///
+/// let cured = overrides.cure("fast ㎧ ꙮk ♡", Options::default()).unwrap();
+///
+/// assert_eq!(cured, "fast m/s ok ");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Overrides {
+  entries: Vec<Entry>,
+}
+
+impl Overrides {
+  /// Creates a new empty table.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { entries: vec![] }
+  }
+
+  fn insert<R: RangeBounds<char>>(mut self, range: R, translation: Translation) -> Self {
+    let start = match range.start_bound() {
+      Bound::Included(&c) => c as u32,
+      Bound::Excluded(&c) => c as u32 + 1,
+      Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+      Bound::Included(&c) => c as u32,
+      Bound::Excluded(&c) => match (c as u32).checked_sub(1) {
+        Some(end) => end,
+        None => return self,
+      },
+      Bound::Unbounded => char::MAX as _,
+    };
+
+    if start > end {
+      return self;
+    }
+
+    let first = self.entries.partition_point(|entry| entry.end < start);
+    let last = first + self.entries[first..].partition_point(|entry| entry.start <= end);
+    let mut replacement = Vec::with_capacity(3);
+
+    // keep the parts of the overlapping entries that aren't covered by the new one.
+    if let Some(entry) = self.entries.get(first)
+      && entry.start < start
+      && first < last
+    {
+      replacement.push(Entry {
+        start: entry.start,
+        end: start - 1,
+        translation: entry.translation.clone(),
+      });
+    }
+
+    let tail = (first < last)
+      .then(|| &self.entries[last - 1])
+      .filter(|entry| entry.end > end)
+      .map(|entry| Entry {
+        start: end + 1,
+        end: entry.end,
+        translation: entry.translation.clone(),
+      });
+
+    replacement.push(Entry {
+      start,
+      end,
+      translation,
+    });
+
+    replacement.extend(tail);
+
+    self.entries.splice(first..last, replacement);
+    self
+  }
+
+  /// Translates a range of codepoints to a single character, which is converted to lowercase.
+  #[must_use]
+  pub fn character<R: RangeBounds<char>>(self, range: R, with: char) -> Self {
+    self.insert(
+      range,
+      Translation::Character(with.to_lowercase().next().unwrap_or(with)),
+    )
+  }
+
+  /// Translates a range of codepoints to a string, which is converted to lowercase.
+  #[must_use]
+  pub fn string<R, S>(self, range: R, with: S) -> Self
+  where
+    R: RangeBounds<char>,
+    S: Into<Cow<'static, str>>,
+  {
+    self.insert(
+      range,
+      Translation::String(CuredString::new(
+        with.into().to_lowercase(),
+        Options::default(),
+      )),
+    )
+  }
+
+  /// Removes a range of codepoints from the output.
+  #[must_use]
+  pub fn remove<R: RangeBounds<char>>(self, range: R) -> Self {
+    self.insert(range, Translation::None)
+  }
+
+  fn get(&self, code: u32) -> Option<&Translation> {
+    let entry = self
+      .entries
+      .get(self.entries.partition_point(|entry| entry.end < code))?;
+
+    (entry.start <= code).then_some(&entry.translation)
+  }
+
+  // translate falls back to the lowercase form, so this has to as well.
+  pub(super) fn contains(&self, code: u32) -> bool {
+    self.get(code).is_some()
+      || char::from_u32(code)
+        .and_then(|character| character.to_lowercase().next())
+        .is_some_and(|lowercased| self.get(lowercased as _).is_some())
+  }
+
+  pub(super) fn affects_ascii(&self) -> bool {
+    self.entries.first().is_some_and(|entry| entry.start < 0x80)
+  }
+
+  #[cfg_attr(not(feature = "options"), allow(unused_variables))]
+  pub(super) fn translate(
+    &self,
+    code: u32,
+    code_lowercased: u32,
+    options: Options,
+  ) -> Option<Translation> {
+    if self.entries.is_empty() {
+      return None;
+    }
+
+    #[cfg_attr(not(feature = "options"), allow(unused_mut))]
+    let mut translation = self
+      .get(code)
+      .or_else(|| self.get(code_lowercased))?
+      .clone();
+
+    #[cfg(feature = "options")]
+    if options.retains(
+      code,
+      CASE_SENSITIVE_CODEPOINTS_OFFSET.into(),
+      CASE_SENSITIVE_CODEPOINTS_COUNT.into(),
+    ) || options.retains(code_lowercased, 6, CODEPOINTS_COUNT.into())
+    {
+      return None;
+    }
+
+    #[cfg(all(feature = "leetspeak", feature = "options"))]
+    if let Translation::String(string) = &mut translation {
+      string.disable_leetspeak = options.is(2);
+      string.disable_alphabetical_leetspeak = options.is(3);
+    }
+
+    #[cfg(feature = "options")]
+    if code != code_lowercased && options.is(0) {
+      translation.make_uppercase();
+    }
+
+    Some(translation)
+  }
+
+  /// Cures a single character/unicode codepoint with the specified [`Options`] while consulting this table first.
+  ///
+  /// For more information, see [the `cure_char` function][super::cure_char()].
+  pub fn cure_char<C: Into<u32>>(&self, code: C, options: Options) -> Translation {
+    super::cure_char_with(code.into(), options, self)
+  }
+
+  /// Cures a string with the specified [`Options`] while consulting this table first.
+  ///
+  /// For more information, see [the `cure` function][super::cure()].
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+  pub fn cure(&self, input: &str, options: Options) -> Result<CuredString, Error> {
+    super::cure_with(input, options, self)
+  }
+
+  /// Cures a string with the specified [`Options`] while consulting this table first and keeping track of which part of the input produced each part of the output.
+  ///
+  /// For more information, see [the `cure_mapped` function][super::cure_mapped()].
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+  pub fn cure_mapped<'a>(
+    &self,
+    input: &'a str,
+    options: Options,
+  ) -> Result<MappedCuredString<'a>, Error> {
+    super::cure_mapped_with(input, options, self)
+  }
+
+  /// Cures a string with the specified [`Options`] while consulting this table first and recording how every character of the input was cured.
+  ///
+  /// For more information, see [the `cure_explained` function][super::cure_explained()].
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+  pub fn cure_explained(&self, input: &str, options: Options) -> Result<Explanation, Error> {
+    super::cure_explained_with(input, options, self)
+  }
+}
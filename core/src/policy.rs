@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{
+  Error, Options, RestrictionLevel,
+  util::{is_bidi_override, is_combining, is_invisible},
+};
+
+/// A rule from an [`IdentifierPolicy`] that an identifier has violated.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Violation {
+  /// The cured identifier is [similar][super::CuredString::eq] to a reserved name, which is included here.
+  Reserved(String),
+
+  /// The identifier contains more [invisible characters](https://www.unicode.org/reports/tr44/#Default_Ignorable_Code_Point) (e.g: zero-width spaces) than allowed.
+  TooManyInvisible {
+    /// The amount of invisible characters in the identifier.
+    count: usize,
+
+    /// The maximum amount of invisible characters allowed.
+    max: usize,
+  },
+
+  /// The identifier contains more diacritics and other combining characters than allowed.
+  TooManyCombining {
+    /// The amount of combining characters in the identifier.
+    count: usize,
+
+    /// The maximum amount of combining characters allowed.
+    max: usize,
+  },
+
+  /// The identifier contains a [bidirectional embedding, override or isolate character](https://en.wikipedia.org/wiki/Bidirectional_text#Explicit_formatting).
+  BidiOverride,
+
+  /// The cured identifier is shorter than allowed.
+  TooShort {
+    /// The amount of non-whitespace characters in the cured identifier.
+    length: usize,
+
+    /// The minimum amount of non-whitespace characters allowed.
+    min: usize,
+  },
+
+  /// The identifier mixes scripts in a way that is less restrictive than allowed, which is included here. See [`RestrictionLevel`] for more information.
+  MixedScript(RestrictionLevel),
+}
+
+/// A configurable set of rules for validating identifiers such as usernames, built on top of [`cure`][super::cure()].
+///
+/// By default, no rule is enforced.
+///
+/// ```rust
+/// use decancer::{IdentifierPolicy, Options, RestrictionLevel, Violation};
+///
+/// let policy = IdentifierPolicy::new()
+///   .reserved(["admin", "moderator"])
+///   .max_invisible(0)
+///   .forbid_bidi_overrides()
+///   .min_length(3)
+///   .restriction_level(RestrictionLevel::HighlyRestrictive);
+///
+/// assert!(policy.is_valid("null8626", Options::default()).unwrap());
+///
+/// assert_eq!(
+///   policy.validate("ＡｄｍＩＮ", Options::default()).unwrap(),
+///   [Violation::Reserved(String::from("admin"))]
+/// );
+///
+/// assert_eq!(
+///   policy.validate("user\u{200b}\u{202e}", Options::default()).unwrap(),
+///   [
+///     Violation::TooManyInvisible { count: 2, max: 0 },
+///     Violation::BidiOverride,
+///   ]
+/// );
+///
+/// assert_eq!(
+///   policy.validate("p\u{0430}", Options::default()).unwrap(),
+///   [
+///     Violation::TooShort { length: 2, min: 3 },
+///     Violation::MixedScript(RestrictionLevel::Unrestricted),
+///   ]
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdentifierPolicy {
+  reserved: Vec<String>,
+  max_invisible: Option<usize>,
+  max_combining: Option<usize>,
+  forbid_bidi_overrides: bool,
+  min_length: usize,
+  restriction_level: RestrictionLevel,
+}
+
+impl IdentifierPolicy {
+  /// Creates a new policy that doesn't enforce any rule.
+  #[must_use]
+  pub const fn new() -> Self {
+    Self {
+      reserved: vec![],
+      max_invisible: None,
+      max_combining: None,
+      forbid_bidi_overrides: false,
+      min_length: 0,
+      restriction_level: RestrictionLevel::Unrestricted,
+    }
+  }
+
+  /// Adds names that the cured identifier must not be [similar][super::CuredString::eq] to.
+  #[must_use]
+  pub fn reserved<S, O>(mut self, names: O) -> Self
+  where
+    S: Into<String>,
+    O: IntoIterator<Item = S>,
+  {
+    self.reserved.extend(names.into_iter().map(Into::into));
+    self
+  }
+
+  /// Sets the maximum amount of [invisible characters](https://www.unicode.org/reports/tr44/#Default_Ignorable_Code_Point) (e.g: zero-width spaces) allowed in the identifier.
+  #[must_use]
+  pub const fn max_invisible(mut self, max: usize) -> Self {
+    self.max_invisible = Some(max);
+    self
+  }
+
+  /// Sets the maximum amount of diacritics and other combining characters allowed in the identifier.
+  #[must_use]
+  pub const fn max_combining(mut self, max: usize) -> Self {
+    self.max_combining = Some(max);
+    self
+  }
+
+  /// Forbids [bidirectional embedding, override and isolate characters](https://en.wikipedia.org/wiki/Bidirectional_text#Explicit_formatting) in the identifier.
+  #[must_use]
+  pub const fn forbid_bidi_overrides(mut self) -> Self {
+    self.forbid_bidi_overrides = true;
+    self
+  }
+
+  /// Sets the minimum amount of non-whitespace characters in the cured identifier.
+  #[must_use]
+  pub const fn min_length(mut self, min: usize) -> Self {
+    self.min_length = min;
+    self
+  }
+
+  /// Sets the least restrictive [`RestrictionLevel`] allowed for the identifier.
+  #[must_use]
+  pub const fn restriction_level(mut self, level: RestrictionLevel) -> Self {
+    self.restriction_level = level;
+    self
+  }
+
+  /// Validates an identifier with the specified [`Options`], and returns every rule that it violates in the order they are declared in [`Violation`]. An empty list means that the identifier is valid.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+  pub fn validate(&self, input: &str, options: Options) -> Result<Vec<Violation>, Error> {
+    let cured = super::cure(input, options)?;
+    let mut violations = vec![];
+
+    if let Some(name) = self.reserved.iter().find(|name| cured == **name) {
+      violations.push(Violation::Reserved(name.clone()));
+    }
+
+    let mut invisible = 0;
+    let mut combining = 0;
+    let mut has_bidi_override = false;
+
+    for character in input.chars() {
+      if is_invisible(character as _) {
+        invisible += 1;
+        has_bidi_override |= is_bidi_override(character as _);
+      } else if is_combining(character) {
+        combining += 1;
+      }
+    }
+
+    if let Some(max) = self.max_invisible.filter(|&max| invisible > max) {
+      violations.push(Violation::TooManyInvisible {
+        count: invisible,
+        max,
+      });
+    }
+
+    if let Some(max) = self.max_combining.filter(|&max| combining > max) {
+      violations.push(Violation::TooManyCombining {
+        count: combining,
+        max,
+      });
+    }
+
+    if self.forbid_bidi_overrides && has_bidi_override {
+      violations.push(Violation::BidiOverride);
+    }
+
+    let length = cured
+      .chars()
+      .filter(|character| !character.is_whitespace())
+      .count();
+
+    if length < self.min_length {
+      violations.push(Violation::TooShort {
+        length,
+        min: self.min_length,
+      });
+    }
+
+    let level = RestrictionLevel::of(input);
+
+    if level > self.restriction_level {
+      violations.push(Violation::MixedScript(level));
+    }
+
+    Ok(violations)
+  }
+
+  /// Returns whether an identifier doesn't violate any rule with the specified [`Options`]. See [`validate`][IdentifierPolicy::validate] for more information.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the string is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+  pub fn is_valid(&self, input: &str, options: Options) -> Result<bool, Error> {
+    self
+      .validate(input, options)
+      .map(|violations| violations.is_empty())
+  }
+}
+
+impl Default for IdentifierPolicy {
+  fn default() -> Self {
+    Self::new()
+  }
+}
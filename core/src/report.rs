@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{
+  CureSource, CureStep, Script, Translation,
+  util::{is_bidi_override, is_combining, is_special_rtl},
+};
+
+/// A report of how disguised a string is, which can be used to flag heavily obfuscated strings even when they don't contain any unwanted words.
+///
+/// This is returned by [`analyze`][super::analyze()] and [`Explanation::report`][super::Explanation::report].
+///
+/// ```rust
+/// use decancer::Script;
+///
+/// let report = decancer::analyze("ｈ̸̢ė̷l͓͊l̸о", decancer::Options::default()).unwrap();
+///
+/// assert_eq!(report.total, 11);
+/// assert_eq!(report.combining_marks, 6);
+/// assert_eq!(report.scripts, [Script::Latin, Script::Cyrillic]);
+/// assert!(report.is_mixed_script());
+/// assert!(!report.has_bidi_override);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Report {
+  /// The amount of codepoints in the input.
+  pub total: usize,
+
+  /// The amount of codepoints that were translated to something else.
+  pub translated: usize,
+
+  /// The amount of codepoints that were left as is, including ASCII characters that were only turned into lowercase.
+  pub unchanged: usize,
+
+  /// The amount of codepoints that were removed from the output.
+  pub removed: usize,
+
+  /// The amount of diacritics and other combining characters in the input, which is commonly used in [Zalgo texts](https://en.wikipedia.org/wiki/Zalgo_text).
+  pub combining_marks: usize,
+
+  /// The amount of [bidirectional control characters](https://en.wikipedia.org/wiki/Bidirectional_text#Explicit_formatting) in the input.
+  pub bidi_controls: usize,
+
+  /// Whether the input contains a [bidirectional embedding, override or isolate character](https://en.wikipedia.org/wiki/Bidirectional_text#Explicit_formatting), which is commonly used to make text appear in a different order than it actually is.
+  pub has_bidi_override: bool,
+
+  /// Every distinct script in the input in a sorted order, excluding [shared scripts][Script::is_shared].
+  pub scripts: Vec<Script>,
+}
+
+impl Report {
+  pub(super) fn new(steps: &[CureStep]) -> Self {
+    let mut report = Self {
+      total: steps.len(),
+      translated: 0,
+      unchanged: 0,
+      removed: 0,
+      combining_marks: 0,
+      bidi_controls: 0,
+      has_bidi_override: false,
+      scripts: vec![],
+    };
+
+    for step in steps {
+      if step.translation == Translation::None {
+        report.removed += 1;
+      } else if matches!(
+        step.source,
+        CureSource::CaseSensitiveTable | CureSource::MainTable | CureSource::Override
+      ) {
+        report.translated += 1;
+      } else {
+        report.unchanged += 1;
+      }
+
+      if is_special_rtl(step.character as _) {
+        report.bidi_controls += 1;
+        report.has_bidi_override |= is_bidi_override(step.character as _);
+      }
+
+      if is_combining(step.character) {
+        report.combining_marks += 1;
+      }
+
+      match Script::of(step.character) {
+        Script::Common | Script::Inherited => {},
+
+        script => report.scripts.push(script),
+      }
+    }
+
+    report.scripts.sort_unstable();
+    report.scripts.dedup();
+    report
+  }
+
+  /// Returns the ratio of codepoints that were translated or removed, from `0.0` (nothing was cured) to `1.0` (everything was cured).
+  #[must_use]
+  #[allow(clippy::cast_precision_loss)]
+  pub fn score(&self) -> f64 {
+    if self.total == 0 {
+      0.0
+    } else {
+      (self.translated + self.removed) as f64 / self.total as f64
+    }
+  }
+
+  /// Returns the average amount of combining characters for every other character in the input. Regular text rarely goes beyond `1.0`, while [Zalgo texts](https://en.wikipedia.org/wiki/Zalgo_text) usually do.
+  #[must_use]
+  #[allow(clippy::cast_precision_loss)]
+  pub fn zalgo_density(&self) -> f64 {
+    self.combining_marks as f64 / (self.total - self.combining_marks).max(1) as f64
+  }
+
+  /// Returns whether the input contains characters from more than one script.
+  #[must_use]
+  pub const fn is_mixed_script(&self) -> bool {
+    self.scripts.len() > 1
+  }
+}
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{
+  Script,
+  script::{self, ScriptSet},
+};
+
+/// A [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection) as defined by unicode, which describes how safely a string mixes scripts.
+///
+/// Levels are ordered from the most restrictive to the least restrictive, so a policy can be enforced by comparing them.
+///
+/// **NOTE:** Unicode's restriction levels also require every character to be allowed by its [identifier profile](https://www.unicode.org/reports/tr39/#General_Security_Profile), which decancer doesn't check. Instead, a string that uses a script that is not [recommended][Script::is_recommended] is always [`Unrestricted`][RestrictionLevel::Unrestricted].
+///
+/// ```rust
+/// use decancer::RestrictionLevel;
+///
+/// assert_eq!(RestrictionLevel::of("hello"), RestrictionLevel::AsciiOnly);
+/// assert_eq!(RestrictionLevel::of("привет"), RestrictionLevel::SingleScript);
+/// assert_eq!(RestrictionLevel::of("p\u{0430}ypal"), RestrictionLevel::Unrestricted);
+/// assert!(RestrictionLevel::of("東京tokyo") <= RestrictionLevel::HighlyRestrictive);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RestrictionLevel {
+  /// Every character is an ASCII character.
+  AsciiOnly,
+
+  /// Every character shares a script in unicode's `Script_Extensions` property. [Han][Script::Han] mixed with [Hiragana][Script::Hiragana] and [Katakana][Script::Katakana] (japanese), with [Bopomofo][Script::Bopomofo], or with [Hangul][Script::Hangul] (korean) also counts as a single script.
+  SingleScript,
+
+  /// Every character is either [latin][Script::Latin] or from [Han][Script::Han] and its japanese, bopomofo or korean scripts as described in [`SingleScript`][RestrictionLevel::SingleScript].
+  HighlyRestrictive,
+
+  /// Every character is either [latin][Script::Latin] or from one other recommended script, which can't be [cyrillic][Script::Cyrillic] or [greek][Script::Greek].
+  ModeratelyRestrictive,
+
+  /// None of the above, or a script that is not [recommended][Script::is_recommended] is used. This also covers unicode's minimally restrictive level.
+  Unrestricted,
+}
+
+// returns the scripts that every set has in common.
+fn resolve<'a>(sets: impl Iterator<Item = &'a ScriptSet>) -> ScriptSet {
+  sets.fold(ScriptSet::ALL, |resolved, &set| resolved.intersection(set))
+}
+
+impl RestrictionLevel {
+  /// Returns the restriction level of a string.
+  #[must_use]
+  pub fn of(input: &str) -> Self {
+    if input.is_ascii() {
+      return Self::AsciiOnly;
+    }
+
+    let sets: Vec<_> = input
+      .chars()
+      .map(ScriptSet::of)
+      .filter(|set| !set.is_shared())
+      .collect();
+
+    if !sets
+      .iter()
+      .all(|set| set.scripts().any(Script::is_recommended))
+    {
+      return Self::Unrestricted;
+    }
+
+    if !resolve(sets.iter()).is_empty() {
+      return Self::SingleScript;
+    }
+
+    let others = resolve(sets.iter().filter(|set| !set.contains(Script::Latin as _)));
+
+    if others.contains(script::HAN_WITH_BOPOMOFO)
+      || others.contains(script::JAPANESE)
+      || others.contains(script::KOREAN)
+    {
+      Self::HighlyRestrictive
+    } else if others
+      .scripts()
+      .any(|script| script.is_recommended() && !matches!(script, Script::Cyrillic | Script::Greek))
+    {
+      Self::ModeratelyRestrictive
+    } else {
+      Self::Unrestricted
+    }
+  }
+}
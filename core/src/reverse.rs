@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{Options, Translation, codepoints::Codepoint};
+use std::{iter, ops::RangeInclusive};
+
+// generated by build.rs, see generate_case_exceptions() for the layout.
+const CASE_EXCEPTIONS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/case_exceptions.bin"));
+
+pub fn case_exceptions() -> impl Iterator<Item = u32> {
+  CASE_EXCEPTIONS
+    .chunks_exact(4)
+    .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn translates_to(code: u32, options: Options, target: &str) -> bool {
+  match super::cure_char(code, options) {
+    Translation::Character(character) => {
+      let mut chars = target.chars();
+
+      chars.next() == Some(character) && chars.next().is_none()
+    },
+
+    Translation::String(string) => string.as_ref() == target,
+
+    Translation::None => target.is_empty(),
+  }
+}
+
+// cure_char looks up a character's lowercase form in the table, so its uppercase form is a candidate as well.
+fn with_uppercase(code: u32) -> impl Iterator<Item = u32> {
+  let uppercase = char::from_u32(code).and_then(|character| {
+    let mut uppercase = character.to_uppercase();
+
+    uppercase.next().filter(|_| uppercase.next().is_none())
+  });
+
+  iter::once(code).chain(uppercase.map(u32::from))
+}
+
+/// Returns every range of characters that [`cure_char`][super::cure_char()] translates to exactly the specified string with the specified [`Options`], in ascending order.
+///
+/// This is the inverse of decancer's translation table, which can be used to generate test data with real confusables or to build blocklists. The comparison is exact, so the target string should already be cured (e.g: in lowercase, unless [`Options::retain_capitalization`] is enabled).
+///
+/// **NOTE:** Only the characters in decancer's translation table, their uppercase forms and the target itself are checked. Characters that are removed or turned into a space without being in the table (e.g: control characters or unassigned codepoints) are not returned, even if the target string is empty or a space.
+///
+/// ```rust
+/// let ranges: Vec<_> = decancer::reverse_cure("a", decancer::Options::default()).collect();
+///
+/// assert!(ranges.iter().any(|range| range.contains(&'a')));
+/// assert!(ranges.iter().any(|range| range.contains(&'Ａ')));
+/// assert!(ranges.iter().any(|range| range.contains(&'а')));
+///
+/// for range in ranges {
+///   for character in range {
+///     assert_eq!(decancer::cure_char(character, decancer::Options::default()), "a");
+///   }
+/// }
+/// ```
+pub fn reverse_cure(target: &str, options: Options) -> impl Iterator<Item = RangeInclusive<char>> {
+  let mut target_chars = target.chars();
+  let target_char = target_chars
+    .next()
+    .filter(|_| target_chars.next().is_none())
+    .map(u32::from);
+
+  let mut codes: Vec<_> = Codepoint::all()
+    .chain(target_char)
+    .flat_map(with_uppercase)
+    .chain(case_exceptions())
+    .filter(|&code| translates_to(code, options, target))
+    .collect();
+
+  codes.sort_unstable();
+  codes.dedup();
+
+  let mut codes = codes.into_iter().peekable();
+
+  iter::from_fn(move || {
+    let start = codes.next()?;
+    let mut end = start;
+
+    while let Some(code) = codes.next_if_eq(&(end + 1)) {
+      end = code;
+    }
+
+    Some(char::from_u32(start)?..=char::from_u32(end)?)
+  })
+}
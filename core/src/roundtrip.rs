@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+//! Functions for (de)serializing a [`CuredString`] as is, which can be used with `#[serde(with = "decancer::roundtrip")]`.
+//!
+//! [`CuredString`] is (de)serialized as a struct with the following fields:
+//!
+//! - `string`: the cured string, which is **not** cured again when deserialized.
+//! - `disable_leetspeak`: whether [`CuredString::disable_leetspeak`] is enabled.
+//! - `disable_alphabetical_leetspeak`: whether [`CuredString::disable_alphabetical_leetspeak`] is enabled.
+//!
+//! **NOTE:** Since the string is not cured again, this should only be used for data that is serialized by this module. Custom [leetspeak rules][CuredString::leetspeak_rules] and [separators][CuredString::separators] are not preserved either.
+
+use super::{CuredString, Options};
+use serde::{
+  Deserializer, Serializer,
+  de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+  ser::SerializeStruct,
+};
+use std::fmt::{self, Formatter};
+
+const FIELDS: &[&str] = &[
+  "string",
+  "disable_leetspeak",
+  "disable_alphabetical_leetspeak",
+];
+
+/// Serializes a cured string along with its leetspeak settings.
+///
+/// # Errors
+///
+/// Errors if the serializer errors.
+pub fn serialize<S>(cured: &CuredString, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  #[cfg(all(feature = "leetspeak", feature = "options"))]
+  let (disable_leetspeak, disable_alphabetical_leetspeak) = (
+    cured.disable_leetspeak,
+    cured.disable_alphabetical_leetspeak,
+  );
+
+  #[cfg(not(all(feature = "leetspeak", feature = "options")))]
+  let (disable_leetspeak, disable_alphabetical_leetspeak) = (false, false);
+
+  let mut state = serializer.serialize_struct("CuredString", FIELDS.len())?;
+
+  state.serialize_field(FIELDS[0], &*cured.string)?;
+  state.serialize_field(FIELDS[1], &disable_leetspeak)?;
+  state.serialize_field(FIELDS[2], &disable_alphabetical_leetspeak)?;
+  state.end()
+}
+
+/// Deserializes a cured string that was serialized by [`serialize`], without curing it again.
+///
+/// # Errors
+///
+/// Errors if the deserializer errors or if the `string` field is missing.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<CuredString, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserializer.deserialize_struct("CuredString", FIELDS, CuredStringVisitor)
+}
+
+// an owned string, which is deserialized through a visitor so that serde's alloc feature is not required.
+struct OwnedString;
+
+impl<'de> DeserializeSeed<'de> for OwnedString {
+  type Value = String;
+
+  fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_str(self)
+  }
+}
+
+impl Visitor<'_> for OwnedString {
+  type Value = String;
+
+  fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str("a string")
+  }
+
+  fn visit_str<E>(self, input: &str) -> Result<Self::Value, E>
+  where
+    E: de::Error,
+  {
+    Ok(input.to_owned())
+  }
+}
+
+struct FieldVisitor;
+
+impl<'de> DeserializeSeed<'de> for FieldVisitor {
+  type Value = usize;
+
+  fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_identifier(self)
+  }
+}
+
+impl Visitor<'_> for FieldVisitor {
+  type Value = usize;
+
+  fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str("a field name")
+  }
+
+  fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+  where
+    E: de::Error,
+  {
+    FIELDS
+      .iter()
+      .position(|&field| field == name)
+      .ok_or_else(|| E::unknown_field(name, FIELDS))
+  }
+}
+
+struct CuredStringVisitor;
+
+impl CuredStringVisitor {
+  #[cfg_attr(
+    not(all(feature = "leetspeak", feature = "options")),
+    allow(unused_variables, unused_mut)
+  )]
+  fn cured(
+    string: String,
+    disable_leetspeak: bool,
+    disable_alphabetical_leetspeak: bool,
+  ) -> CuredString {
+    let mut cured = CuredString::new(string, Options::default());
+
+    #[cfg(all(feature = "leetspeak", feature = "options"))]
+    {
+      cured.disable_leetspeak(disable_leetspeak);
+      cured.disable_alphabetical_leetspeak(disable_alphabetical_leetspeak);
+    }
+
+    cured
+  }
+}
+
+impl<'de> Visitor<'de> for CuredStringVisitor {
+  type Value = CuredString;
+
+  fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str("a cured string")
+  }
+
+  fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+  where
+    A: SeqAccess<'de>,
+  {
+    let string = seq
+      .next_element_seed(OwnedString)?
+      .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+    let disable_leetspeak = seq.next_element()?.unwrap_or_default();
+    let disable_alphabetical_leetspeak = seq.next_element()?.unwrap_or_default();
+
+    Ok(Self::cured(
+      string,
+      disable_leetspeak,
+      disable_alphabetical_leetspeak,
+    ))
+  }
+
+  fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+  where
+    A: MapAccess<'de>,
+  {
+    let mut string = None;
+    let mut disable_leetspeak = false;
+    let mut disable_alphabetical_leetspeak = false;
+
+    while let Some(field) = map.next_key_seed(FieldVisitor)? {
+      match field {
+        0 => string = Some(map.next_value_seed(OwnedString)?),
+
+        1 => disable_leetspeak = map.next_value()?,
+
+        _ => disable_alphabetical_leetspeak = map.next_value()?,
+      }
+    }
+
+    Ok(Self::cured(
+      string.ok_or_else(|| de::Error::missing_field(FIELDS[0]))?,
+      disable_leetspeak,
+      disable_alphabetical_leetspeak,
+    ))
+  }
+}
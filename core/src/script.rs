@@ -0,0 +1,346 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::util::{Binary, CODEPOINT_MASK, numbered_enum};
+
+const SCRIPTS: Binary = Binary::new(include_bytes!("../bin/scripts.bin"));
+
+const SCRIPT_EXTENSIONS_OFFSET: u16 = SCRIPTS.u16_at(0);
+const SCRIPT_EXTENSION_SETS_OFFSET: u16 = SCRIPTS.u16_at(2);
+
+// - 1 because we're only using them in binary search
+const SCRIPTS_COUNT: u16 = ((SCRIPT_EXTENSIONS_OFFSET - 4) / 6) - 1;
+const SCRIPT_EXTENSIONS_COUNT: u16 =
+  ((SCRIPT_EXTENSION_SETS_OFFSET - SCRIPT_EXTENSIONS_OFFSET) / 6) - 1;
+
+// indices reserved by scripts/update_unicode.mjs, which are not in scripts.bin.
+const UNKNOWN: u16 = 252;
+pub const HAN_WITH_BOPOMOFO: u16 = 253;
+pub const JAPANESE: u16 = 254;
+pub const KOREAN: u16 = 255;
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn search(code: u32, offset: u16, count: u16) -> Option<u16> {
+  let mut start = 0i32;
+  let mut end = count as i32;
+
+  while start <= end {
+    let mid = start.midpoint(end);
+    let offset = ((offset as i32) + (mid * 6)) as _;
+    let kv = SCRIPTS.u32_at(offset);
+
+    let other = kv & CODEPOINT_MASK;
+
+    if code < other {
+      end = mid - 1;
+    } else if code > (other + SCRIPTS.u16_at(offset + 4) as u32) {
+      start = mid + 1;
+    } else {
+      return Some((kv >> 20) as _);
+    }
+  }
+
+  None
+}
+
+numbered_enum! {
+  /// A [unicode script](https://en.wikipedia.org/wiki/Script_(Unicode)) that a character belongs to, according to unicode's `Script` property.
+  ///
+  /// This only names the scripts that are either [recommended][Script::is_recommended] or commonly used in confusables, every other script is [`Other`][Script::Other].
+  #[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+  pub enum Script: u8 {
+    /// Characters that are shared by multiple scripts, like digits, punctuation, symbols and emojis.
+    Common = 0,
+
+    /// Characters that inherit the script of the character before them, like diacritics.
+    Inherited = 1,
+
+    /// The [latin script](https://en.wikipedia.org/wiki/Latin_script).
+    Latin = 2,
+
+    /// The [greek script](https://en.wikipedia.org/wiki/Greek_alphabet).
+    Greek = 3,
+
+    /// The [coptic script](https://en.wikipedia.org/wiki/Coptic_alphabet).
+    Coptic = 4,
+
+    /// The [cyrillic script](https://en.wikipedia.org/wiki/Cyrillic_script).
+    Cyrillic = 5,
+
+    /// The [glagolitic script](https://en.wikipedia.org/wiki/Glagolitic_script).
+    Glagolitic = 6,
+
+    /// The [armenian script](https://en.wikipedia.org/wiki/Armenian_alphabet).
+    Armenian = 7,
+
+    /// The [georgian script](https://en.wikipedia.org/wiki/Georgian_scripts).
+    Georgian = 8,
+
+    /// The [hebrew script](https://en.wikipedia.org/wiki/Hebrew_alphabet).
+    Hebrew = 9,
+
+    /// The [arabic script](https://en.wikipedia.org/wiki/Arabic_script).
+    Arabic = 10,
+
+    /// The [thaana script](https://en.wikipedia.org/wiki/Thaana).
+    Thaana = 11,
+
+    /// The [devanagari script](https://en.wikipedia.org/wiki/Devanagari).
+    Devanagari = 12,
+
+    /// The [bengali script](https://en.wikipedia.org/wiki/Bengali%E2%80%93Assamese_script).
+    Bengali = 13,
+
+    /// The [gurmukhi script](https://en.wikipedia.org/wiki/Gurmukhi).
+    Gurmukhi = 14,
+
+    /// The [gujarati script](https://en.wikipedia.org/wiki/Gujarati_script).
+    Gujarati = 15,
+
+    /// The [odia script](https://en.wikipedia.org/wiki/Odia_script).
+    Oriya = 16,
+
+    /// The [tamil script](https://en.wikipedia.org/wiki/Tamil_script).
+    Tamil = 17,
+
+    /// The [telugu script](https://en.wikipedia.org/wiki/Telugu_script).
+    Telugu = 18,
+
+    /// The [kannada script](https://en.wikipedia.org/wiki/Kannada_script).
+    Kannada = 19,
+
+    /// The [malayalam script](https://en.wikipedia.org/wiki/Malayalam_script).
+    Malayalam = 20,
+
+    /// The [sinhala script](https://en.wikipedia.org/wiki/Sinhala_script).
+    Sinhala = 21,
+
+    /// The [thai script](https://en.wikipedia.org/wiki/Thai_script).
+    Thai = 22,
+
+    /// The [lao script](https://en.wikipedia.org/wiki/Lao_script).
+    Lao = 23,
+
+    /// The [tibetan script](https://en.wikipedia.org/wiki/Tibetan_script).
+    Tibetan = 24,
+
+    /// The [burmese script](https://en.wikipedia.org/wiki/Burmese_alphabet).
+    Myanmar = 25,
+
+    /// The [khmer script](https://en.wikipedia.org/wiki/Khmer_script).
+    Khmer = 26,
+
+    /// The [mongolian script](https://en.wikipedia.org/wiki/Mongolian_script).
+    Mongolian = 27,
+
+    /// The [ethiopic script](https://en.wikipedia.org/wiki/Ge%CA%BDez_script).
+    Ethiopic = 28,
+
+    /// The [cherokee script](https://en.wikipedia.org/wiki/Cherokee_syllabary).
+    Cherokee = 29,
+
+    /// The [canadian aboriginal syllabics](https://en.wikipedia.org/wiki/Canadian_Aboriginal_syllabics).
+    CanadianAboriginal = 30,
+
+    /// The [runic script](https://en.wikipedia.org/wiki/Runes).
+    Runic = 31,
+
+    /// The [tifinagh script](https://en.wikipedia.org/wiki/Tifinagh).
+    Tifinagh = 32,
+
+    /// The [lisu script](https://en.wikipedia.org/wiki/Fraser_alphabet).
+    Lisu = 33,
+
+    /// The [vai script](https://en.wikipedia.org/wiki/Vai_syllabary).
+    Vai = 34,
+
+    /// [Chinese characters](https://en.wikipedia.org/wiki/Chinese_characters), including japanese kanji.
+    Han = 35,
+
+    /// The [hiragana script](https://en.wikipedia.org/wiki/Hiragana).
+    Hiragana = 36,
+
+    /// The [katakana script](https://en.wikipedia.org/wiki/Katakana).
+    Katakana = 37,
+
+    /// The [bopomofo script](https://en.wikipedia.org/wiki/Bopomofo).
+    Bopomofo = 38,
+
+    /// The [korean script](https://en.wikipedia.org/wiki/Hangul).
+    Hangul = 39,
+
+    /// The [braille script](https://en.wikipedia.org/wiki/Braille).
+    Braille = 40,
+
+    /// Any other script, or a character that is not assigned to any script (e.g: unassigned or private use characters).
+    Other = 41
+  }
+}
+
+impl Script {
+  #[allow(clippy::cast_possible_truncation)]
+  const fn from_index(index: u16) -> Self {
+    if index < Self::Other as u16 {
+      Self::from_number(index as _)
+    } else {
+      Self::Other
+    }
+  }
+
+  /// Returns the script that a character belongs to.
+  ///
+  /// ```rust
+  /// use decancer::Script;
+  ///
+  /// assert_eq!(Script::of('a'), Script::Latin);
+  /// assert_eq!(Script::of('а'), Script::Cyrillic);
+  /// assert_eq!(Script::of('!'), Script::Common);
+  /// ```
+  #[must_use]
+  pub const fn of(character: char) -> Self {
+    match search(character as _, 4, SCRIPTS_COUNT) {
+      Some(index) => Self::from_index(index),
+      None => Self::Other,
+    }
+  }
+
+  /// Returns whether this script is [recommended for use in identifiers](https://www.unicode.org/reports/tr31/#Table_Recommended_Scripts) by unicode. Strings that use other scripts are always [`Unrestricted`][super::RestrictionLevel::Unrestricted].
+  #[must_use]
+  pub const fn is_recommended(self) -> bool {
+    !matches!(
+      self,
+      Self::Coptic
+        | Self::Glagolitic
+        | Self::Mongolian
+        | Self::Cherokee
+        | Self::CanadianAboriginal
+        | Self::Runic
+        | Self::Tifinagh
+        | Self::Lisu
+        | Self::Vai
+        | Self::Braille
+        | Self::Other
+    )
+  }
+
+  /// Returns whether this script is shared with other scripts, i.e. [`Common`][Script::Common] or [`Inherited`][Script::Inherited].
+  #[must_use]
+  pub const fn is_shared(self) -> bool {
+    matches!(self, Self::Common | Self::Inherited)
+  }
+}
+
+// a set of every script in unicode's Script_Extensions property of a character, augmented as described in https://www.unicode.org/reports/tr39/#Mixed_Script_Detection.
+// unlike Script, scripts that are not named are still distinct.
+#[derive(Copy, Clone)]
+pub struct ScriptSet([u64; 4]);
+
+impl ScriptSet {
+  pub(super) const ALL: Self = Self([u64::MAX; 4]);
+
+  const fn with(mut self, index: u16) -> Self {
+    self.0[(index / 64) as usize] |= 1 << (index % 64);
+    self
+  }
+
+  pub(super) const fn contains(self, index: u16) -> bool {
+    self.0[(index / 64) as usize] & (1 << (index % 64)) != 0
+  }
+
+  // returns the scripts that every character in a string has in common, ignoring characters that are shared by every script.
+  #[cfg(feature = "confusables")]
+  pub(super) fn resolved(input: &str) -> Self {
+    input
+      .chars()
+      .map(Self::of)
+      .filter(|set| !set.is_shared())
+      .fold(Self::ALL, Self::intersection)
+  }
+
+  pub(super) fn of(character: char) -> Self {
+    let code = character as u32;
+
+    search(code, SCRIPT_EXTENSIONS_OFFSET, SCRIPT_EXTENSIONS_COUNT)
+      .map_or_else(
+        || Self([0; 4]).with(search(code, 4, SCRIPTS_COUNT).unwrap_or(UNKNOWN)),
+        |offset| {
+          let offset = (SCRIPT_EXTENSION_SETS_OFFSET + offset) as usize;
+
+          SCRIPTS
+            .sliced(offset + 1, SCRIPTS.at(offset) as _)
+            .iter()
+            .fold(Self([0; 4]), |set, &index| set.with(index.into()))
+        },
+      )
+      .augmented()
+  }
+
+  const fn augmented(mut self) -> Self {
+    if self.contains(Script::Han as _) {
+      self = self.with(HAN_WITH_BOPOMOFO).with(JAPANESE).with(KOREAN);
+    }
+
+    if self.contains(Script::Hiragana as _) || self.contains(Script::Katakana as _) {
+      self = self.with(JAPANESE);
+    }
+
+    if self.contains(Script::Hangul as _) {
+      self = self.with(KOREAN);
+    }
+
+    if self.contains(Script::Bopomofo as _) {
+      self = self.with(HAN_WITH_BOPOMOFO);
+    }
+
+    self
+  }
+
+  #[must_use]
+  pub(super) const fn intersection(mut self, other: Self) -> Self {
+    let mut idx = 0;
+
+    while idx < 4 {
+      self.0[idx] &= other.0[idx];
+      idx += 1;
+    }
+
+    self
+  }
+
+  pub(super) const fn is_empty(self) -> bool {
+    matches!(self.0, [0, 0, 0, 0])
+  }
+
+  pub(super) const fn is_shared(self) -> bool {
+    self.contains(Script::Common as _) || self.contains(Script::Inherited as _)
+  }
+
+  // returns every named script in this set.
+  pub(super) fn scripts(self) -> impl Iterator<Item = Script> {
+    (0..Script::Other as u16)
+      .filter(move |&index| self.contains(index))
+      .map(Script::from_index)
+  }
+}
+
+/// Returns every distinct script used in a string in a sorted order, excluding [shared scripts][Script::is_shared].
+///
+/// ```rust
+/// use decancer::Script;
+///
+/// assert_eq!(decancer::scripts("hello"), [Script::Latin]);
+/// assert_eq!(decancer::scripts("p\u{0430}ypal"), [Script::Latin, Script::Cyrillic]);
+/// assert!(decancer::scripts("123!").is_empty());
+/// ```
+#[must_use]
+pub fn scripts(input: &str) -> Vec<Script> {
+  let mut scripts: Vec<_> = input
+    .chars()
+    .map(Script::of)
+    .filter(|script| !script.is_shared())
+    .collect();
+
+  scripts.sort_unstable();
+  scripts.dedup();
+  scripts
+}
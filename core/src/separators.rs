@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::similar::to_lowercase;
+
+const WHITESPACE: u8 = 1 << 0;
+const PUNCTUATION: u8 = 1 << 1;
+const DIGITS: u8 = 1 << 2;
+const OTHERS: u8 = 1 << 3;
+
+/// A configuration struct where you can customize how [`CuredString`][super::CuredString]'s comparison methods treat separators between similar-looking characters (e.g: `h.e.l.l.o` or `h e l l o`).
+///
+/// By default, decancer treats any run of the same non-alphabetical character as a separator.
+///
+/// ```rust
+/// use decancer::Separators;
+///
+/// let mut cured = decancer::cure!("h.e.l.l.o").unwrap();
+///
+/// assert_eq!(cured, "hello");
+///
+/// cured.separators(Separators::STRICT);
+///
+/// assert_ne!(cured, "hello");
+///
+/// cured.separators(Separators::STRICT.punctuation().max_run_length(1));
+///
+/// assert_eq!(cured, "hello");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Separators {
+  classes: u8,
+  max_run_length: usize,
+  mixed: bool,
+}
+
+impl Separators {
+  /// A configuration where any run of the same non-alphabetical character is treated as a separator. This is the default.
+  pub const LENIENT: Self = Self {
+    classes: WHITESPACE | PUNCTUATION | DIGITS | OTHERS,
+    max_run_length: usize::MAX,
+    mixed: false,
+  };
+
+  /// A configuration where no character is treated as a separator.
+  pub const STRICT: Self = Self {
+    classes: 0,
+    max_run_length: usize::MAX,
+    mixed: false,
+  };
+
+  /// Allows whitespace characters to be treated as separators.
+  #[must_use]
+  pub const fn whitespace(self) -> Self {
+    Self {
+      classes: self.classes | WHITESPACE,
+      ..self
+    }
+  }
+
+  /// Allows ASCII punctuation characters (e.g: `.`, `-` or `/`) to be treated as separators.
+  #[must_use]
+  pub const fn punctuation(self) -> Self {
+    Self {
+      classes: self.classes | PUNCTUATION,
+      ..self
+    }
+  }
+
+  /// Allows ASCII digits to be treated as separators.
+  ///
+  /// **NOTE:** This has no effect on digits that are part of a leetspeak match.
+  #[must_use]
+  pub const fn digits(self) -> Self {
+    Self {
+      classes: self.classes | DIGITS,
+      ..self
+    }
+  }
+
+  /// Allows every other non-alphabetical character to be treated as separators, including characters retained by [`Options`][super::Options].
+  #[must_use]
+  pub const fn others(self) -> Self {
+    Self {
+      classes: self.classes | OTHERS,
+      ..self
+    }
+  }
+
+  /// Limits how many separator characters can appear between two similar-looking characters.
+  #[must_use]
+  pub const fn max_run_length(self, max_run_length: usize) -> Self {
+    Self {
+      max_run_length,
+      ..self
+    }
+  }
+
+  /// Allows different separator characters to be mixed between two similar-looking characters (e.g: `h.-e` or `h. e`).
+  #[must_use]
+  pub const fn mixed(self) -> Self {
+    Self {
+      mixed: true,
+      ..self
+    }
+  }
+
+  pub(super) fn allows(self, character: char, first: Option<char>, run_length: usize) -> bool {
+    let class = if character.is_ascii_alphabetic() {
+      return false;
+    } else if character.is_whitespace() {
+      WHITESPACE
+    } else if character.is_ascii_punctuation() {
+      PUNCTUATION
+    } else if character.is_ascii_digit() {
+      DIGITS
+    } else {
+      OTHERS
+    };
+
+    (self.classes & class) != 0
+      && run_length < self.max_run_length
+      && (self.mixed || first.is_none_or(|first| to_lowercase(character) == to_lowercase(first)))
+  }
+}
+
+impl Default for Separators {
+  fn default() -> Self {
+    Self::LENIENT
+  }
+}
@@ -0,0 +1,417 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+#[cfg(feature = "separators")]
+use super::Separators;
+use super::{CuredStr, Match, similar, util::merge_ranges};
+#[cfg(feature = "leetspeak")]
+use super::{LeetspeakRules, leetspeak};
+use std::ops::Range;
+
+struct Node {
+  character: char,
+  first: char,
+  children: Vec<(char, usize)>,
+  patterns: Vec<usize>,
+}
+
+impl Node {
+  const fn new(character: char, first: char) -> Self {
+    Self {
+      character,
+      first,
+      children: vec![],
+      patterns: vec![],
+    }
+  }
+}
+
+#[derive(Copy, Clone)]
+struct State {
+  node: usize,
+  end: usize,
+  #[cfg(feature = "separators")]
+  separator: Option<char>,
+  #[cfg(feature = "separators")]
+  separator_run_length: usize,
+}
+
+struct Scanner<'a> {
+  nodes: &'a [Node],
+  text: &'a str,
+  #[cfg(feature = "leetspeak")]
+  leetspeak_rules: Option<&'a LeetspeakRules>,
+  #[cfg(feature = "leetspeak")]
+  leetspeak_matches: Vec<(char, Option<usize>)>,
+  #[cfg(all(feature = "leetspeak", feature = "options"))]
+  disable_leetspeak: bool,
+  #[cfg(all(feature = "leetspeak", feature = "options"))]
+  disable_alphabetical_leetspeak: bool,
+  #[cfg(feature = "separators")]
+  separators: Separators,
+  next: Vec<State>,
+  pending: Vec<State>,
+  found: Vec<usize>,
+}
+
+impl<'a> Scanner<'a> {
+  fn new(set: &'a MatcherSet, cured: &'a CuredStr<'_>) -> Self {
+    Self {
+      nodes: &set.nodes,
+      text: cured,
+      #[cfg(feature = "leetspeak")]
+      leetspeak_rules: cured.leetspeak_rules.as_deref(),
+      #[cfg(feature = "leetspeak")]
+      leetspeak_matches: vec![],
+      #[cfg(all(feature = "leetspeak", feature = "options"))]
+      disable_leetspeak: cured.disable_leetspeak,
+      #[cfg(all(feature = "leetspeak", feature = "options"))]
+      disable_alphabetical_leetspeak: cured.disable_alphabetical_leetspeak,
+      #[cfg(feature = "separators")]
+      separators: cured.separators,
+      next: vec![],
+      pending: vec![],
+      found: vec![],
+    }
+  }
+
+  #[cfg(feature = "leetspeak")]
+  fn matches_leetspeak(&mut self, index: usize, other_char: char) -> Option<usize> {
+    #[cfg(feature = "options")]
+    if self.disable_leetspeak {
+      return None;
+    }
+
+    let other_char = similar::to_lowercase(other_char);
+
+    // leetspeak results only depend on the position, so they are shared by every candidate.
+    if let Some(&(_, matched_len)) = self
+      .leetspeak_matches
+      .iter()
+      .find(|(character, _)| *character == other_char)
+    {
+      return matched_len;
+    }
+
+    #[cfg(feature = "options")]
+    let regexes = if self.disable_alphabetical_leetspeak {
+      &leetspeak::NON_ALPHABETICAL_REGEXES
+    } else {
+      &leetspeak::REGEXES
+    };
+
+    #[cfg(not(feature = "options"))]
+    let regexes = &leetspeak::REGEXES;
+
+    let matched_len = leetspeak::find(
+      self.leetspeak_rules,
+      regexes,
+      &self.text.as_bytes()[index..],
+      other_char,
+    );
+
+    self.leetspeak_matches.push((other_char, matched_len));
+
+    matched_len
+  }
+
+  // returns the amount of bytes consumed, just like Matcher::matches.
+  #[cfg_attr(
+    not(feature = "leetspeak"),
+    allow(clippy::unused_self, clippy::needless_pass_by_ref_mut)
+  )]
+  fn matches(&mut self, index: usize, self_char: char, other_char: char) -> Option<usize> {
+    if similar::is(self_char, other_char) {
+      return Some(self_char.len_utf8());
+    }
+
+    #[cfg(feature = "leetspeak")]
+    return self.matches_leetspeak(index, other_char);
+
+    #[cfg(not(feature = "leetspeak"))]
+    {
+      let _ = index;
+
+      None
+    }
+  }
+
+  fn advance(&mut self, target: usize, index: usize, character: char, other_char: char) -> bool {
+    let Some(matched_skip) = self.matches(index, character, other_char) else {
+      return false;
+    };
+
+    let new_state = State {
+      node: target,
+      end: index + matched_skip,
+      #[cfg(feature = "separators")]
+      separator: None,
+      #[cfg(feature = "separators")]
+      separator_run_length: 0,
+    };
+
+    if !self.nodes[target].patterns.is_empty() {
+      self.found.push(target);
+    }
+
+    if matched_skip == character.len_utf8() {
+      self.next.push(new_state);
+    } else {
+      self.pending.push(new_state);
+    }
+
+    true
+  }
+
+  // returns true if the state's last character is repeated, just like Matcher::next.
+  fn transition(&mut self, state: &State, index: usize, character: char) -> bool {
+    let nodes = self.nodes;
+    let node = &nodes[state.node];
+
+    for &(other_char, target) in &node.children {
+      self.advance(target, index, character, other_char);
+    }
+
+    // strings that don't continue with any of the children still have to be kept track of.
+    state.node != 0 && self.advance(state.node, index, character, node.character)
+  }
+
+  // returns every node with a pattern that could have been matched.
+  fn scan(mut self) -> Vec<usize> {
+    let mut active = vec![];
+
+    let root = State {
+      node: 0,
+      end: 0,
+      #[cfg(feature = "separators")]
+      separator: None,
+      #[cfg(feature = "separators")]
+      separator_run_length: 0,
+    };
+
+    for (index, character) in self.text.char_indices() {
+      #[cfg(feature = "leetspeak")]
+      self.leetspeak_matches.clear();
+
+      let mut i = 0;
+
+      while i < self.pending.len() {
+        if self.pending[i].end <= index {
+          let state = self.pending.swap_remove(i);
+
+          if state.end == index {
+            active.push(state);
+          }
+        } else {
+          i += 1;
+        }
+      }
+
+      self.transition(&root, index, character);
+
+      #[cfg_attr(not(feature = "separators"), allow(clippy::needless_continue))]
+      for state in &active {
+        if self.transition(state, index, character)
+          || self
+            .matches(index, character, self.nodes[state.node].first)
+            .is_some()
+        {
+          continue;
+        }
+
+        #[cfg(feature = "separators")]
+        if self
+          .separators
+          .allows(character, state.separator, state.separator_run_length)
+        {
+          self.next.push(State {
+            separator: state.separator.or(Some(character)),
+            separator_run_length: state.separator_run_length + 1,
+            ..*state
+          });
+        }
+      }
+
+      // candidates at the same position in the trie behave the same from here on.
+      self.next.sort_unstable_by_key(|state| {
+        (
+          state.node,
+          #[cfg(feature = "separators")]
+          state.separator,
+          #[cfg(feature = "separators")]
+          state.separator_run_length,
+        )
+      });
+
+      self.next.dedup_by_key(|state| {
+        (
+          state.node,
+          #[cfg(feature = "separators")]
+          state.separator,
+          #[cfg(feature = "separators")]
+          state.separator_run_length,
+        )
+      });
+
+      active.clear();
+      active.append(&mut self.next);
+    }
+
+    self.found
+  }
+}
+
+/// A precompiled set of strings that can be searched for in a [`CuredString`][super::CuredString] all at once.
+///
+/// This is an alternative to [`CuredString::find_multiple`][super::CuredString::find_multiple] for large arrays of strings (e.g: blocklists), and returns the exact same results. The strings are compiled into a [trie](https://en.wikipedia.org/wiki/Trie) only once. Searching then takes a single pass over the cured string to find which strings could match, using the same similar-looking character, leetspeak and separator rules as [`Matcher`][super::Matcher]. Only those strings are then searched for with [`CuredString::find`][super::CuredString::find].
+///
+/// **NOTE:** This is not an [Aho-Corasick](https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm) automaton. Similarity between characters is not transitive and matches can skip over repeated characters, separators and leetspeak, thus the trie has no failure links. Instead, every partial match is kept track of at the same time, so the single pass is slower when a lot of partial matches overlap.
+///
+/// ```rust
+/// use decancer::MatcherSet;
+///
+/// let set = MatcherSet::new(["hello", "oh yeah"]);
+/// let cured = decancer::cure!("ｈꡩ𝔏┕⊕𝚑ᅠΎ⫕ᣲ𑀜").unwrap();
+///
+/// assert_eq!(set.find(&cured), [0..11]);
+/// assert_eq!(set.find(&cured), cured.find_multiple(["hello", "oh yeah"]));
+/// ```
+pub struct MatcherSet {
+  nodes: Vec<Node>,
+  patterns: Vec<String>,
+}
+
+impl MatcherSet {
+  /// Compiles an array of strings into a new matcher set.
+  ///
+  /// Empty strings are ignored.
+  #[must_use]
+  pub fn new<S, O>(other: O) -> Self
+  where
+    S: AsRef<str>,
+    O: IntoIterator<Item = S>,
+  {
+    let mut nodes = vec![Node::new('\0', '\0')];
+    let mut patterns = vec![];
+
+    for (pattern_index, pattern) in other.into_iter().enumerate() {
+      let pattern = pattern.as_ref();
+      let mut current = 0;
+
+      for character in pattern.chars().map(similar::to_lowercase) {
+        current = if let Some(&(_, child)) = nodes[current]
+          .children
+          .iter()
+          .find(|(other_character, _)| *other_character == character)
+        {
+          child
+        } else {
+          let first = if current == 0 {
+            character
+          } else {
+            nodes[current].first
+          };
+
+          nodes.push(Node::new(character, first));
+
+          let child = nodes.len() - 1;
+
+          nodes[current].children.push((character, child));
+          child
+        };
+      }
+
+      if current != 0 {
+        nodes[current].patterns.push(pattern_index);
+      }
+
+      patterns.push(pattern.to_owned());
+    }
+
+    Self { nodes, patterns }
+  }
+
+  // returns the index of every string that could be found in the cured string, in ascending order.
+  fn candidates(&self, cured: &CuredStr<'_>) -> Vec<usize> {
+    let mut candidates: Vec<_> = Scanner::new(self, cured)
+      .scan()
+      .into_iter()
+      .flat_map(|node| self.nodes[node].patterns.iter().copied())
+      .collect();
+
+    candidates.sort_unstable();
+    candidates.dedup();
+    candidates
+  }
+
+  /// Searches for every string in this set inside a cured string, and returns a [`Vec`] of every similar-looking match along with the index of the string that produced it.
+  ///
+  /// This returns the same matches as [`CuredString::find_multiple_indexed`][super::CuredString::find_multiple_indexed], sorted by their position in the cured string.
+  ///
+  /// This comparison is case-insensitive.
+  ///
+  /// ```rust
+  /// use decancer::{Match, MatcherSet};
+  ///
+  /// let set = MatcherSet::new(["hello", "world"]);
+  /// let cured = decancer::cure!("oh, hello there w0rld!").unwrap();
+  /// let matches = set.matches(&cured);
+  ///
+  /// assert_eq!(matches, [
+  ///   Match { index: 0, range: 4..9 },
+  ///   Match { index: 1, range: 16..21 },
+  /// ]);
+  /// ```
+  #[must_use]
+  pub fn matches(&self, cured: &CuredStr<'_>) -> Vec<Match> {
+    let mut matches = vec![];
+
+    for index in self.candidates(cured) {
+      matches.extend(
+        cured
+          .find(&self.patterns[index])
+          .map(|range| Match { index, range }),
+      );
+    }
+
+    matches.sort_by_key(|mat| (mat.range.start, mat.index));
+    matches
+  }
+
+  /// Searches for every string in this set inside a cured string, and returns a [`Vec`] of every similar-looking match, where overlapping matches are merged together.
+  ///
+  /// This returns the same ranges as [`CuredString::find_multiple`][super::CuredString::find_multiple].
+  ///
+  /// This comparison is case-insensitive.
+  ///
+  /// ```rust
+  /// use decancer::MatcherSet;
+  ///
+  /// let set = MatcherSet::new(["hello", "world"]);
+  /// let cured = decancer::cure!("oh, hello there w0rld!").unwrap();
+  ///
+  /// assert_eq!(set.find(&cured), [4..9, 16..21]);
+  /// ```
+  #[must_use]
+  pub fn find(&self, cured: &CuredStr<'_>) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+
+    for index in self.candidates(cured) {
+      ranges.extend(cured.find(&self.patterns[index]));
+    }
+
+    merge_ranges(&mut ranges);
+    ranges
+  }
+
+  /// Checks if a cured string similarly contains any of the strings in this set.
+  ///
+  /// This comparison is case-insensitive.
+  #[must_use]
+  pub fn is_match(&self, cured: &CuredStr<'_>) -> bool {
+    self
+      .candidates(cured)
+      .into_iter()
+      .any(|index| cured.find(&self.patterns[index]).next().is_some())
+  }
+}
@@ -1,52 +1,245 @@
 // SPDX-License-Identifier: MIT
 // SPDX-FileCopyrightText: 2021-2026 null8626
 
+#[cfg(feature = "separators")]
+use super::Separators;
 #[cfg(feature = "leetspeak")]
-use super::leetspeak;
+use super::{LeetspeakRules, leetspeak};
 use super::{codepoints::CODEPOINTS, util::Cached};
-use std::{char, iter::FusedIterator, ops::Range};
+use std::{
+  char,
+  iter::{self, FusedIterator},
+  ops::Range,
+};
 
 pub const SIMILAR_START: u16 = CODEPOINTS.u16_at(2);
 pub const SIMILAR_END: u16 = CODEPOINTS.u16_at(4);
 
-fn to_lowercase(c: char) -> char {
+pub fn to_lowercase(c: char) -> char {
   c.to_lowercase().next().unwrap_or(c)
 }
 
-#[cfg(feature = "separators")]
-fn is_exact(self_char: char, other_char: char) -> bool {
-  to_lowercase(self_char) == to_lowercase(other_char)
+const fn mark_group(mut table: [u128; 128], start: u16, end: u16) -> [u128; 128] {
+  let mut a = start;
+
+  while a < end {
+    let mut b = start;
+
+    while b < end {
+      table[(CODEPOINTS.at(a as _) & 0x7f) as usize] |= 1 << (CODEPOINTS.at(b as _) & 0x7f);
+      b += 1;
+    }
+
+    a += 1;
+  }
+
+  table
+}
+
+// a 128x128 bitset of every pair of similar ASCII characters, computed at compile time.
+const SIMILAR: [u128; 128] = {
+  let mut table = [0; 128];
+  let mut idx = 0;
+
+  while idx < 128 {
+    table[idx] = 1 << idx;
+    idx += 1;
+  }
+
+  let mut start = SIMILAR_START;
+  let mut offset = SIMILAR_START;
+
+  // the last character of every group has its highest bit set.
+  while offset < SIMILAR_END {
+    if CODEPOINTS.at(offset as _) >= 0x80 {
+      table = mark_group(table, start, offset + 1);
+      start = offset + 1;
+    }
+
+    offset += 1;
+  }
+
+  mark_group(table, start, SIMILAR_END)
+};
+
+const fn root(parents: &[u8; 128], mut code: u8) -> u8 {
+  while parents[code as usize] != code {
+    code = parents[code as usize];
+  }
+
+  code
+}
+
+// the representative of every ASCII character, computed at compile time.
+// similarity is not transitive, so every character is merged with every character that it is transitively similar to (i.e: a union-find over SIMILAR), where the smallest letter in the class is the representative, or the smallest character if there is none.
+// uppercase characters are skipped since comparisons are case-insensitive.
+const REPRESENTATIVES: [u8; 128] = {
+  let mut parents = [0; 128];
+  let mut a = 0u8;
+
+  while a < 128 {
+    parents[a as usize] = a;
+    a += 1;
+  }
+
+  a = 0;
+
+  while a < 128 {
+    let mut b = 0u8;
+
+    while b < 128 {
+      if !a.is_ascii_uppercase() && !b.is_ascii_uppercase() && (SIMILAR[a as usize] >> b) & 1 != 0 {
+        let root_a = root(&parents, a);
+        let root_b = root(&parents, b);
+
+        // the smaller root always wins, so every root is the smallest character in its class.
+        if root_a < root_b {
+          parents[root_b as usize] = root_a;
+        } else {
+          parents[root_a as usize] = root_b;
+        }
+      }
+
+      b += 1;
+    }
+
+    a += 1;
+  }
+
+  let mut table = [0; 128];
+
+  a = 0;
+
+  while a < 128 {
+    let class = root(&parents, a);
+    let mut letter = b'a';
+
+    table[a as usize] = class;
+
+    while letter <= b'z' {
+      if root(&parents, letter) == class {
+        table[a as usize] = letter;
+        break;
+      }
+
+      letter += 1;
+    }
+
+    a += 1;
+  }
+
+  table
+};
+
+// returns the same character for every character in the same class of similar-looking characters.
+pub fn representative(character: char) -> char {
+  let character = to_lowercase(character);
+
+  if character.is_ascii() {
+    char::from(REPRESENTATIVES[character as usize])
+  } else {
+    character
+  }
 }
 
-#[allow(clippy::cast_possible_truncation)]
 pub fn is(self_char: char, other_char: char) -> bool {
   let self_char = to_lowercase(self_char) as u32;
   let other_char = to_lowercase(other_char) as u32;
 
-  if self_char == other_char {
-    return true;
-  } else if self_char <= 0x7f && other_char <= 0x7f {
-    let mut id = 0;
+  self_char == other_char
+    || (self_char <= 0x7f
+      && other_char <= 0x7f
+      && (SIMILAR[self_char as usize] >> other_char) & 1 != 0)
+}
+
+/// Returns whether two characters are considered similar-looking by decancer.
+///
+/// This is the same comparison used by [`CuredString::find`][super::CuredString::find], [`CuredString`'s `==` operator][super::CuredString::eq] and other comparison methods. It is case-insensitive. Only ASCII characters can be similar to other characters, any other character is only similar to itself.
+///
+/// ```rust
+/// assert!(decancer::is_similar('l', 'I'));
+/// assert!(decancer::is_similar('o', '0'));
+/// assert!(decancer::is_similar('a', 'A'));
+/// assert!(!decancer::is_similar('a', 'b'));
+/// ```
+#[must_use]
+pub fn is_similar(a: char, b: char) -> bool {
+  is(a, b)
+}
+
+/// Returns every character that is [similar][is_similar] to the specified character in ascending order, including the lowercase form of the character itself.
+///
+/// This can be used to expand search queries or to build validators that share decancer's notion of similar-looking characters.
+///
+/// ```rust
+/// let chars: String = decancer::similar_chars('O').collect();
+///
+/// assert_eq!(chars, "#0oq");
+/// assert_eq!(decancer::similar_chars('é').collect::<Vec<_>>(), ['é']);
+/// ```
+pub fn similar_chars(character: char) -> impl Iterator<Item = char> {
+  let character = to_lowercase(character);
+
+  let (mut bits, other) = if character.is_ascii() {
+    (SIMILAR[character as usize], None)
+  } else {
+    (0, Some(character))
+  };
+
+  iter::from_fn(move || {
+    if bits == 0 {
+      return None;
+    }
+
+    let code = bits.trailing_zeros();
 
-    for offset in SIMILAR_START..SIMILAR_END {
+    bits &= bits - 1;
+    char::from_u32(code)
+  })
+  .chain(other)
+}
+
+/// Returns every group of similar-looking ASCII characters known by decancer, in lowercase.
+///
+/// Every character in a group is [similar][is_similar] to every other character in the same group. A character can be in more than one group, but similarity is not transitive: `'s'` is similar to `'5'` and `'z'`, but `'5'` is not similar to `'z'`.
+///
+/// ```rust
+/// let groups: Vec<_> = decancer::similar_groups().collect();
+///
+/// assert!(groups.contains(&vec!['o', '0', 'q', '#']));
+/// assert!(groups.iter().any(|group| group.contains(&'s') && group.contains(&'5')));
+/// ```
+pub fn similar_groups() -> impl Iterator<Item = Vec<char>> {
+  let mut offset = SIMILAR_START;
+
+  iter::from_fn(move || {
+    let mut group = vec![];
+
+    while offset < SIMILAR_END {
       let cur = CODEPOINTS.at(offset as _);
-      let sim = cur & 0x7f;
 
-      if sim == (self_char as u8) {
-        id |= 1;
-      } else if sim == (other_char as u8) {
-        id |= 2;
-      }
+      offset += 1;
+      group.push(char::from(cur & 0x7f));
 
-      if id == 3 {
-        return true;
-      } else if cur >= 0x80 {
-        id = 0;
+      if cur >= 0x80 {
+        break;
       }
     }
-  }
 
-  false
+    (!group.is_empty()).then_some(group)
+  })
+}
+
+/// A similar-looking match of one of several strings, which also reports which string produced it.
+///
+/// This is yielded by [`CuredString::find_multiple_indexed`][super::CuredString::find_multiple_indexed] and [`MatcherSet::matches`][super::MatcherSet::matches].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Match {
+  /// The index of the string that produced this match, in the same order as they were passed.
+  pub index: usize,
+
+  /// The non-inclusive range of this match in the cured string.
+  pub range: Range<usize>,
 }
 
 struct ExplicitStartingPosition {
@@ -69,6 +262,10 @@ pub struct Matcher<'a, 'b> {
   disable_leetspeak: bool,
   #[cfg(all(feature = "leetspeak", feature = "options"))]
   disable_alphabetical_leetspeak: bool,
+  #[cfg(feature = "leetspeak")]
+  leetspeak_rules: Option<&'a LeetspeakRules>,
+  #[cfg(feature = "separators")]
+  separators: Separators,
 }
 
 impl<'a, 'b> Matcher<'a, 'b> {
@@ -77,6 +274,8 @@ impl<'a, 'b> Matcher<'a, 'b> {
     other_str: &'b str,
     #[cfg(all(feature = "leetspeak", feature = "options"))] disable_leetspeak: bool,
     #[cfg(all(feature = "leetspeak", feature = "options"))] disable_alphabetical_leetspeak: bool,
+    #[cfg(feature = "leetspeak")] leetspeak_rules: Option<&'a LeetspeakRules>,
+    #[cfg(feature = "separators")] separators: Separators,
   ) -> Self {
     if other_str.is_empty() || self_str.len() < other_str.len() {
       self_str = "";
@@ -94,6 +293,10 @@ impl<'a, 'b> Matcher<'a, 'b> {
       disable_leetspeak,
       #[cfg(all(feature = "leetspeak", feature = "options"))]
       disable_alphabetical_leetspeak,
+      #[cfg(feature = "leetspeak")]
+      leetspeak_rules,
+      #[cfg(feature = "separators")]
+      separators,
     }
   }
 
@@ -101,13 +304,14 @@ impl<'a, 'b> Matcher<'a, 'b> {
   fn matches_leetspeak(&mut self, other_char: char) -> Option<usize> {
     let haystack = &self.self_str[self.self_index..];
     let matched_len = leetspeak::find(
+      self.leetspeak_rules,
       if self.disable_alphabetical_leetspeak {
         &leetspeak::NON_ALPHABETICAL_REGEXES
       } else {
         &leetspeak::REGEXES
       },
       haystack.as_bytes(),
-      other_char as _,
+      other_char,
     )?;
 
     self.self_iterator = haystack[matched_len..].chars().into();
@@ -150,6 +354,8 @@ impl<'a, 'b> Matcher<'a, 'b> {
     other_str: &'b str,
     #[cfg(all(feature = "leetspeak", feature = "options"))] disable_leetspeak: bool,
     #[cfg(all(feature = "leetspeak", feature = "options"))] disable_alphabetical_leetspeak: bool,
+    #[cfg(feature = "leetspeak")] leetspeak_rules: Option<&'a LeetspeakRules>,
+    #[cfg(feature = "separators")] separators: Separators,
   ) -> bool {
     let mut iter = Self::new(
       self_str,
@@ -158,6 +364,10 @@ impl<'a, 'b> Matcher<'a, 'b> {
       disable_leetspeak,
       #[cfg(all(feature = "leetspeak", feature = "options"))]
       disable_alphabetical_leetspeak,
+      #[cfg(feature = "leetspeak")]
+      leetspeak_rules,
+      #[cfg(feature = "separators")]
+      separators,
     );
 
     iter
@@ -169,7 +379,6 @@ impl<'a, 'b> Matcher<'a, 'b> {
     self.other_iterator.restart();
 
     let current_other = self.other_iterator.next_peek()?;
-    let mut skipped = 0;
 
     if let Some(explicit_starting_position) = self.explicit_starting_position.take() {
       self
@@ -181,17 +390,18 @@ impl<'a, 'b> Matcher<'a, 'b> {
       return Some(current_other);
     }
 
+    // self_index has to be kept up to date here since leetspeak matches start from it.
     loop {
       let next_self_char = self.self_iterator.next()?;
 
       if let Some(matched_skip) = self.matches(next_self_char, current_other.0) {
-        self.start_index = self.self_index + skipped;
-        self.self_index = self.start_index + matched_skip;
+        self.start_index = self.self_index;
+        self.self_index += matched_skip;
 
         return Some(current_other);
       }
 
-      skipped += next_self_char.len_utf8();
+      self.self_index += next_self_char.len_utf8();
     }
   }
 }
@@ -208,6 +418,9 @@ impl Iterator for Matcher<'_, '_> {
     #[cfg(feature = "separators")]
     let mut current_separator = None;
 
+    #[cfg(feature = "separators")]
+    let mut separator_run_length = 0;
+
     while let Some(next_self_char) = self.self_iterator.next() {
       if let Some(matched_skip) = current_other
         .1
@@ -219,6 +432,7 @@ impl Iterator for Matcher<'_, '_> {
         #[cfg(feature = "separators")]
         {
           current_separator = None;
+          separator_run_length = 0;
         }
 
         match self.other_iterator.next_peek() {
@@ -242,6 +456,7 @@ impl Iterator for Matcher<'_, '_> {
         #[cfg(feature = "separators")]
         {
           current_separator = None;
+          separator_run_length = 0;
         }
       } else {
         if let Some(matched_skip) = self.matches(next_self_char, first_other) {
@@ -264,29 +479,20 @@ impl Iterator for Matcher<'_, '_> {
         self.self_index += next_self_char.len_utf8();
 
         #[cfg(feature = "separators")]
-        match current_separator {
-          Some(separator) => {
-            if !is_exact(next_self_char, separator) {
-              if completed {
-                return Some(self.start_index..last_match_end);
-              }
-
-              current_separator = None;
-              current_other = self.restart()?;
-            }
-          },
-
-          None => {
-            if next_self_char.is_ascii_alphabetic() {
-              if completed {
-                return Some(self.start_index..last_match_end);
-              }
+        if self
+          .separators
+          .allows(next_self_char, current_separator, separator_run_length)
+        {
+          current_separator.get_or_insert(next_self_char);
+          separator_run_length += 1;
+        } else {
+          if completed {
+            return Some(self.start_index..last_match_end);
+          }
 
-              current_other = self.restart()?;
-            } else {
-              current_separator.replace(next_self_char);
-            }
-          },
+          current_separator = None;
+          separator_run_length = 0;
+          current_other = self.restart()?;
         }
 
         #[cfg(not(feature = "separators"))]
@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{Class, Curer, Error, Options, util::is_none};
+use std::{
+  io::{self, BufRead, Read},
+  mem, str,
+};
+
+const READ_BUFFER_SIZE: usize = 8192;
+
+/// A curer that takes its input in chunks and cures it incrementally.
+///
+/// Since [bidirectional reordering](https://en.wikipedia.org/wiki/Bidirectional_text) can move characters anywhere within a paragraph, the input is only cured once a paragraph separator (e.g: a newline) is reached, or when [`finish`][StreamCurer::finish] is called. Therefore, the memory usage depends on the length of the longest paragraph rather than the length of the whole input. If [`Options::disable_bidi`] is enabled, the input is cured right away.
+///
+/// UTF-8 sequences that are split between chunks are handled properly, while invalid UTF-8 sequences are replaced with [`U+FFFD`][char::REPLACEMENT_CHARACTER].
+///
+/// ```rust
+/// use decancer::StreamCurer;
+///
+/// let mut curer = StreamCurer::new(decancer::Options::default());
+/// let mut output = String::new();
+/// let input = "vＥⓡ𝔂 𝔽𝕌Ňℕｙ\nţ乇𝕏𝓣".as_bytes();
+///
+/// for chunk in input.chunks(5) {
+///   curer.push(chunk, &mut output).unwrap();
+/// }
+///
+/// assert_eq!(output, "very funny\n");
+///
+/// curer.finish(&mut output).unwrap();
+///
+/// assert_eq!(output, "very funny\ntext");
+/// ```
+#[derive(Clone)]
+pub struct StreamCurer {
+  curer: Curer,
+  incomplete: Vec<u8>,
+  pending: String,
+}
+
+impl StreamCurer {
+  /// Creates a new streaming curer with the specified [`Options`].
+  #[must_use]
+  pub fn new(options: Options) -> Self {
+    Self {
+      curer: Curer::new(options),
+      incomplete: vec![],
+      pending: String::new(),
+    }
+  }
+
+  fn decode(&mut self, chunk: &[u8]) {
+    let owned;
+
+    let mut bytes = if self.incomplete.is_empty() {
+      chunk
+    } else {
+      self.incomplete.extend_from_slice(chunk);
+      owned = mem::take(&mut self.incomplete);
+
+      &owned[..]
+    };
+
+    loop {
+      match str::from_utf8(bytes) {
+        Ok(valid) => {
+          self.pending.push_str(valid);
+
+          return;
+        },
+
+        Err(err) => {
+          let (valid, rest) = bytes.split_at(err.valid_up_to());
+
+          self.pending.push_str(str::from_utf8(valid).unwrap());
+
+          // an incomplete sequence can only be at the end of the chunk.
+          let Some(len) = err.error_len() else {
+            self.incomplete.extend_from_slice(rest);
+
+            return;
+          };
+
+          self.pending.push(char::REPLACEMENT_CHARACTER);
+          bytes = &rest[len..];
+        },
+      }
+    }
+  }
+
+  fn flush(&mut self, end: usize, output: &mut String) -> Result<(), Error> {
+    let result = self.curer.cure_into(&self.pending[..end], output);
+
+    self.pending.drain(..end);
+
+    result
+  }
+
+  /// Pushes a chunk of UTF-8 bytes to this curer, and appends the cured form of every paragraph that has been completed so far to `output`.
+  ///
+  /// # Errors
+  ///
+  /// Errors if a paragraph is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled. The malformed paragraph is discarded, so this curer can still be used afterwards.
+  pub fn push(&mut self, chunk: &[u8], output: &mut String) -> Result<(), Error> {
+    let start = self.pending.len();
+
+    self.decode(chunk);
+
+    #[cfg(feature = "options")]
+    if self.curer.options().is(1) {
+      return self.flush(self.pending.len(), output);
+    }
+
+    // only the newly pushed text needs to be checked for paragraph separators.
+    match self.pending[start..]
+      .char_indices()
+      .rev()
+      .find(|&(_, character)| {
+        !is_none(character as _) && Class::new(character as _) == Some(Class::B)
+      }) {
+      Some((idx, character)) => self.flush(start + idx + character.len_utf8(), output),
+
+      None => Ok(()),
+    }
+  }
+
+  /// Pushes a chunk of text to this curer. See [`push`][StreamCurer::push] for more information.
+  ///
+  /// # Errors
+  ///
+  /// Errors if a paragraph is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+  pub fn push_str(&mut self, chunk: &str, output: &mut String) -> Result<(), Error> {
+    self.push(chunk.as_bytes(), output)
+  }
+
+  /// Cures everything that has been pushed to this curer so far and appends it to `output`, even if the last paragraph is not completed yet. This curer can be reused afterwards.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the last paragraph is malformed to the point where it's not possible to apply unicode's [bidirectional algorithm](https://en.wikipedia.org/wiki/Bidirectional_text) to it. This error is possible if [`Options::disable_bidi`] is disabled.
+  pub fn finish(&mut self, output: &mut String) -> Result<(), Error> {
+    if !self.incomplete.is_empty() {
+      self.incomplete.clear();
+      self.pending.push(char::REPLACEMENT_CHARACTER);
+    }
+
+    self.flush(self.pending.len(), output)
+  }
+}
+
+/// A reader that cures everything that it reads from another reader.
+///
+/// This is a wrapper around a [`StreamCurer`] that implements [`Read`] and [`BufRead`]. Errors from curing the input are returned as an [`io::Error`] with the [`InvalidData`][io::ErrorKind::InvalidData] kind.
+///
+/// ```rust
+/// use decancer::CuredReader;
+/// use std::io::BufRead;
+///
+/// let input = "vＥⓡ𝔂 𝔽𝕌Ňℕｙ\nţ乇𝕏𝓣".as_bytes();
+/// let reader = CuredReader::new(input, decancer::Options::default());
+/// let lines: Vec<_> = reader.lines().collect::<Result<_, _>>().unwrap();
+///
+/// assert_eq!(lines, ["very funny", "text"]);
+/// ```
+pub struct CuredReader<R> {
+  inner: R,
+  curer: StreamCurer,
+  input: Box<[u8]>,
+  output: String,
+  position: usize,
+  finished: bool,
+}
+
+impl<R: Read> CuredReader<R> {
+  /// Creates a new reader that cures everything from another reader with the specified [`Options`].
+  #[must_use]
+  pub fn new(inner: R, options: Options) -> Self {
+    Self {
+      inner,
+      curer: StreamCurer::new(options),
+      input: vec![0; READ_BUFFER_SIZE].into_boxed_slice(),
+      output: String::new(),
+      position: 0,
+      finished: false,
+    }
+  }
+
+  /// Unwraps this reader, returning the underlying reader.
+  ///
+  /// **NOTE:** Anything that has been read from the underlying reader but not from this reader is lost.
+  pub fn into_inner(self) -> R {
+    self.inner
+  }
+}
+
+impl<R: Read> BufRead for CuredReader<R> {
+  fn fill_buf(&mut self) -> io::Result<&[u8]> {
+    while self.position == self.output.len() && !self.finished {
+      self.output.clear();
+      self.position = 0;
+
+      // just like std's BufReader, reads that were interrupted are retried.
+      let size = match self.inner.read(&mut self.input) {
+        Ok(size) => size,
+        Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+        Err(err) => return Err(err),
+      };
+
+      if size == 0 {
+        self.finished = true;
+        self.curer.finish(&mut self.output)
+      } else {
+        self.curer.push(&self.input[..size], &mut self.output)
+      }
+      .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    }
+
+    Ok(&self.output.as_bytes()[self.position..])
+  }
+
+  fn consume(&mut self, amount: usize) {
+    self.position = (self.position + amount).min(self.output.len());
+  }
+}
+
+impl<R: Read> Read for CuredReader<R> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    let available = self.fill_buf()?;
+    let size = available.len().min(buf.len());
+
+    buf[..size].copy_from_slice(&available[..size]);
+    self.consume(size);
+
+    Ok(size)
+  }
+}
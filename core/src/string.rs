@@ -1,29 +1,89 @@
 // SPDX-License-Identifier: MIT
 // SPDX-FileCopyrightText: 2021-2026 null8626
 
-use super::{Matcher, util::merge_ranges};
+#[cfg(feature = "leetspeak")]
+use super::LeetspeakRules;
+#[cfg(feature = "separators")]
+use super::Separators;
+use super::{Match, Matcher, Options, canonical::canonical_key, util::merge_ranges};
+#[cfg(feature = "leetspeak")]
+use std::sync::Arc;
 use std::{
   borrow::Cow,
   fmt::{self, Debug, Display, Formatter},
+  hash::{Hash, Hasher},
   ops::{Deref, Range},
 };
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde::{
+  Deserialize, Deserializer, Serialize, Serializer,
+  de::{self, DeserializeSeed, Visitor},
+};
 
-/// A small wrapper around a [`Cow<'static, str>`] for comparison purposes.
+/// A small wrapper around a [`Cow<'a, str>`] for comparison purposes.
 ///
 /// This is used because imperfections from translations can happen, thus this is used to provide comparison functions that are not as strict and can detect similar-looking characters (e.g: `i` and `l`)
-#[derive(Clone, Eq, Hash)]
-pub struct CuredString {
-  pub(super) string: Cow<'static, str>,
+///
+/// The string can borrow the input it was cured from if curing it didn't change anything, which is only done by [`cure_cow`][super::cure_cow()]. Every other function returns a [`CuredString`], which always owns its string.
+///
+/// **NOTE:** This struct's [`Hash`] implementation only considers the string itself and the leetspeak [`Options`] it was cured with, unlike its comparison functions. Custom leetspeak rules and separators are not hashed. Use [`SimilarKey`][super::SimilarKey] to store cured strings in a [`HashMap`][std::collections::HashMap] or a [`HashSet`][std::collections::HashSet].
+#[derive(Clone, Eq)]
+pub struct CuredStr<'a> {
+  pub(super) string: Cow<'a, str>,
   #[cfg(all(feature = "leetspeak", feature = "options"))]
   pub(super) disable_leetspeak: bool,
   #[cfg(all(feature = "leetspeak", feature = "options"))]
   pub(super) disable_alphabetical_leetspeak: bool,
+  #[cfg(feature = "leetspeak")]
+  pub(super) leetspeak_rules: Option<Arc<LeetspeakRules>>,
+  #[cfg(feature = "separators")]
+  pub(super) separators: Separators,
+}
+
+/// A [`CuredStr`] that owns its string. This is returned by [`cure`][super::cure()] and most other functions.
+pub type CuredString = CuredStr<'static>;
+
+impl<'a> CuredStr<'a> {
+  pub(super) fn new<S: Into<Cow<'a, str>>>(
+    string: S,
+    #[cfg_attr(
+      not(all(feature = "leetspeak", feature = "options")),
+      allow(unused_variables)
+    )]
+    options: Options,
+  ) -> Self {
+    Self {
+      string: string.into(),
+      #[cfg(all(feature = "leetspeak", feature = "options"))]
+      disable_leetspeak: options.is(2),
+      #[cfg(all(feature = "leetspeak", feature = "options"))]
+      disable_alphabetical_leetspeak: options.is(3),
+      #[cfg(feature = "leetspeak")]
+      leetspeak_rules: None,
+      #[cfg(feature = "separators")]
+      separators: Separators::LENIENT,
+    }
+  }
+
+  /// Converts this cured string to a [`CuredString`] that owns its string, copying it if it's borrowed.
+  #[must_use]
+  pub fn into_owned(self) -> CuredString {
+    CuredStr {
+      string: Cow::Owned(self.string.into_owned()),
+      #[cfg(all(feature = "leetspeak", feature = "options"))]
+      disable_leetspeak: self.disable_leetspeak,
+      #[cfg(all(feature = "leetspeak", feature = "options"))]
+      disable_alphabetical_leetspeak: self.disable_alphabetical_leetspeak,
+      #[cfg(feature = "leetspeak")]
+      leetspeak_rules: self.leetspeak_rules,
+      #[cfg(feature = "separators")]
+      separators: self.separators,
+    }
+  }
 }
 
-impl CuredString {
+impl CuredStr<'_> {
   /// Iterates throughout this string and yields every similar-looking match.
   ///
   /// If you plan on using this method with an array of strings, use [`find_multiple`][CuredString::find_multiple].
@@ -46,11 +106,17 @@ impl CuredString {
       self.disable_leetspeak,
       #[cfg(all(feature = "leetspeak", feature = "options"))]
       self.disable_alphabetical_leetspeak,
+      #[cfg(feature = "leetspeak")]
+      self.leetspeak_rules.as_deref(),
+      #[cfg(feature = "separators")]
+      self.separators,
     )
   }
 
   /// Iterates throughout this string and returns a [`Vec`] of every similar-looking match. Unlike [`find`][CuredString::find], this method also takes note of overlapping matches and merges them together.
   ///
+  /// If you plan on searching for a large array of strings repeatedly (e.g: a blocklist), use [`MatcherSet`][super::MatcherSet] instead.
+  ///
   /// This comparison is case-insensitive.
   ///
   /// ```rust
@@ -86,6 +152,39 @@ impl CuredString {
     ranges
   }
 
+  /// Iterates throughout this string and returns a [`Vec`] of every similar-looking match from an array of strings, along with the index of the string that produced it. Unlike [`find_multiple`][CuredString::find_multiple], matches are not merged together.
+  ///
+  /// The matches are sorted by their position in this string.
+  ///
+  /// This comparison is case-insensitive.
+  ///
+  /// ```rust
+  /// use decancer::Match;
+  ///
+  /// let cured = decancer::cure!("wow hello wow heellllo!").unwrap();
+  /// let matches = cured.find_multiple_indexed(["wow", "hello"]);
+  ///
+  /// assert_eq!(matches[0], Match { index: 0, range: 0..3 });
+  /// assert_eq!(matches[1], Match { index: 1, range: 4..9 });
+  /// assert_eq!(matches[2], Match { index: 0, range: 10..13 });
+  /// assert_eq!(matches[3], Match { index: 1, range: 14..22 });
+  /// ```
+  pub fn find_multiple_indexed<S, O>(&self, other: O) -> Vec<Match>
+  where
+    S: AsRef<str>,
+    O: IntoIterator<Item = S>,
+  {
+    let other = other.into_iter();
+    let mut matches = Vec::with_capacity(other.size_hint().0);
+
+    for (index, o) in other.enumerate() {
+      matches.extend(self.find(o.as_ref()).map(|range| Match { index, range }));
+    }
+
+    matches.sort_by_key(|mat| (mat.range.start, mat.index));
+    matches
+  }
+
   fn censor_inner<I>(&mut self, original: &str, matches: I, with: char)
   where
     I: IntoIterator<Item = Range<usize>>,
@@ -249,6 +348,10 @@ impl CuredString {
         self.disable_leetspeak,
         #[cfg(all(feature = "leetspeak", feature = "options"))]
         self.disable_alphabetical_leetspeak,
+        #[cfg(feature = "leetspeak")]
+        self.leetspeak_rules.as_deref(),
+        #[cfg(feature = "separators")]
+        self.separators,
       )
     })
   }
@@ -263,6 +366,28 @@ impl CuredString {
     iter.next().is_some()
   }
 
+  /// Returns a key where separators are removed, every leetspeak spelling (e.g: `|-|` or `vv`) and every class of similar-looking characters is collapsed into a single representative, and repeated characters are removed. This respects this string's leetspeak and separator settings.
+  ///
+  /// Since decancer's notion of similarity is not transitive (see [`similar_groups`][super::similar_groups()]), characters are collapsed with every character that they are transitively similar to, which makes this key fairly loose. This key is not meant to be displayed. See [`SimilarKey`][super::SimilarKey] for a wrapper that uses this key for hashing and comparisons.
+  ///
+  /// **NOTE:** Two strings with the same key are not necessarily [equal][CuredStr::eq] (e.g: `"helo"` and `"hello"` have the same key, but `"helo"` is not equal to `"hello"`). Two equal strings usually have the same key, but not always, since a leetspeak spelling can't always be told apart from the characters around it (e.g: `i^` is a spelling of `p`, but it is also `i` followed by `^`, which is similar to `n`).
+  ///
+  /// ```rust
+  /// let cured = decancer::cure!("|-|3LL0 w.o.r.l.d").unwrap();
+  ///
+  /// assert_eq!(cured.canonical_key(), decancer::cure!("helloworld").unwrap().canonical_key());
+  /// assert_ne!(cured.canonical_key(), decancer::cure!("goodbye").unwrap().canonical_key());
+  ///
+  /// let cured = decancer::cure!("helo").unwrap();
+  ///
+  /// assert_ne!(cured, "hello");
+  /// assert_eq!(cured.canonical_key(), decancer::cure!("hello").unwrap().canonical_key());
+  /// ```
+  #[must_use]
+  pub fn canonical_key(&self) -> String {
+    canonical_key(self)
+  }
+
   /// Prevents decancer from applying leetspeak comparisons in comparison methods.
   #[cfg(all(feature = "leetspeak", feature = "options"))]
   pub const fn disable_leetspeak(&mut self, switch: bool) {
@@ -274,9 +399,23 @@ impl CuredString {
   pub const fn disable_alphabetical_leetspeak(&mut self, switch: bool) {
     self.disable_alphabetical_leetspeak = switch;
   }
+
+  /// Uses a custom set of leetspeak rules in comparison methods. See [`LeetspeakRules`] for more information.
+  ///
+  /// **NOTE:** [`disable_leetspeak`][CuredString::disable_leetspeak] disables these rules as well, while [`disable_alphabetical_leetspeak`][CuredString::disable_alphabetical_leetspeak] only applies to decancer's built-in leetspeak rules.
+  #[cfg(feature = "leetspeak")]
+  pub fn leetspeak_rules<R: Into<Arc<LeetspeakRules>>>(&mut self, rules: R) {
+    self.leetspeak_rules.replace(rules.into());
+  }
+
+  /// Customizes how decancer treats separators between similar-looking characters in comparison methods. See [`Separators`] for more information.
+  #[cfg(feature = "separators")]
+  pub const fn separators(&mut self, separators: Separators) {
+    self.separators = separators;
+  }
 }
 
-impl AsRef<str> for CuredString {
+impl AsRef<str> for CuredStr<'_> {
   /// Coerces this cured string to a [`str`].
   ///
   /// **NOTE:** It's highly **NOT** recommended to use Rust's comparison methods after calling this, and since the string output is laid out in memory the same way as it were to be displayed graphically, displaying it **may not display correctly** since some right-to-left characters are reversed.  
@@ -285,7 +424,7 @@ impl AsRef<str> for CuredString {
   }
 }
 
-impl Deref for CuredString {
+impl Deref for CuredStr<'_> {
   type Target = str;
 
   /// Coerces this cured string to a [`str`].
@@ -296,25 +435,25 @@ impl Deref for CuredString {
   }
 }
 
-impl From<CuredString> for Cow<'static, str> {
-  /// Coerces this cured string to a [`Cow<'static, str>`].
+impl<'a> From<CuredStr<'a>> for Cow<'a, str> {
+  /// Coerces this cured string to a [`Cow<'a, str>`].
   ///
   /// **NOTE:** It's highly **NOT** recommended to use Rust's comparison methods after calling this, and since the string output is laid out in memory the same way as it were to be displayed graphically, displaying it **may not display correctly** since some right-to-left characters are reversed.  
-  fn from(s: CuredString) -> Self {
+  fn from(s: CuredStr<'a>) -> Self {
     s.string
   }
 }
 
-impl From<CuredString> for String {
+impl From<CuredStr<'_>> for String {
   /// Coerces this cured string to a [`String`].
   ///
   /// **NOTE:** It's highly **NOT** recommended to use Rust's comparison methods after calling this, and since the string output is laid out in memory the same way as it were to be displayed graphically, displaying it **may not display correctly** since some right-to-left characters are reversed.  
-  fn from(s: CuredString) -> Self {
+  fn from(s: CuredStr<'_>) -> Self {
     s.string.into_owned()
   }
 }
 
-impl<S> PartialEq<S> for CuredString
+impl<S> PartialEq<S> for CuredStr<'_>
 where
   S: AsRef<str> + ?Sized,
 {
@@ -329,17 +468,33 @@ where
       self.disable_leetspeak,
       #[cfg(all(feature = "leetspeak", feature = "options"))]
       self.disable_alphabetical_leetspeak,
+      #[cfg(feature = "leetspeak")]
+      self.leetspeak_rules.as_deref(),
+      #[cfg(feature = "separators")]
+      self.separators,
     )
   }
 }
 
-impl Debug for CuredString {
+impl Hash for CuredStr<'_> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.string.hash(state);
+
+    #[cfg(all(feature = "leetspeak", feature = "options"))]
+    {
+      self.disable_leetspeak.hash(state);
+      self.disable_alphabetical_leetspeak.hash(state);
+    }
+  }
+}
+
+impl Debug for CuredStr<'_> {
   fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
     Debug::fmt(&**self, f)
   }
 }
 
-impl Display for CuredString {
+impl Display for CuredStr<'_> {
   fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
     Display::fmt(&**self, f)
   }
@@ -347,7 +502,7 @@ impl Display for CuredString {
 
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-impl Serialize for CuredString {
+impl Serialize for CuredStr<'_> {
   fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
   where
     S: Serializer,
@@ -359,11 +514,61 @@ impl Serialize for CuredString {
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 impl<'de> Deserialize<'de> for CuredString {
+  /// Deserializes a string and cures it with decancer's default options. Use [`CuredStringSeed`] to cure it with other [`Options`] instead.
   fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
   where
     D: Deserializer<'de>,
   {
-    Deserialize::deserialize(deserializer)
-      .and_then(|s: &str| super::cure!(s).map_err(de::Error::custom))
+    CuredStringSeed::new(Options::default()).deserialize(deserializer)
+  }
+}
+
+/// A [`DeserializeSeed`] that deserializes a string and cures it with the specified [`Options`].
+///
+/// Unlike [`CuredString`]'s [`Deserialize`] implementation, this allows the use of [`Options`] other than the default ones. Both accept borrowed and owned strings (e.g: JSON strings with escape sequences).
+///
+/// To preserve a cured string as is instead of curing it again, use [`roundtrip`][super::roundtrip].
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Copy, Clone)]
+pub struct CuredStringSeed {
+  options: Options,
+}
+
+#[cfg(feature = "serde")]
+impl CuredStringSeed {
+  /// Creates a new seed that cures the deserialized string with the specified [`Options`].
+  #[must_use]
+  pub const fn new(options: Options) -> Self {
+    Self { options }
+  }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> DeserializeSeed<'de> for CuredStringSeed {
+  type Value = CuredString;
+
+  fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_str(self)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl Visitor<'_> for CuredStringSeed {
+  type Value = CuredString;
+
+  fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str("a string")
+  }
+
+  fn visit_str<E>(self, input: &str) -> Result<Self::Value, E>
+  where
+    E: de::Error,
+  {
+    super::cure(input, self.options).map_err(E::custom)
   }
 }
@@ -1,13 +1,28 @@
 // SPDX-License-Identifier: MIT
 // SPDX-FileCopyrightText: 2021-2026 null8626
 
+#[cfg(feature = "bidi")]
+use super::BidiInfo;
+#[cfg(feature = "confusables")]
+use super::ConfusableKind;
+#[cfg(feature = "leetspeak")]
+use super::LeetspeakRules;
 #[cfg(feature = "options")]
 use super::Options;
+#[cfg(feature = "separators")]
+use super::Separators;
 use super::{
-  Class, Level,
+  Class, CureSource, CuredReader, Curer, IdentifierPolicy, Level, Match, MatcherSet, Overrides,
+  RestrictionLevel, Script, SimilarKey, StreamCurer, Translation, Violation,
   bidi::{IsolatingRunSequence, Paragraph},
 };
-use std::ops::Range;
+use std::{
+  borrow::Cow,
+  collections::HashSet,
+  hash::{BuildHasher, RandomState},
+  io::{self, BufRead, Read},
+  ops::Range,
+};
 
 use proptest::prelude::*;
 
@@ -23,6 +38,44 @@ proptest! {
   fn string_crash(s in "\\PC*") {
     let _ = super::cure!(&s);
   }
+
+  #[test]
+  fn cured_ascii(s in "[\\x00-\\x7fA-Z\u{ff21}\u{5d0}]*") {
+    // overriding an ASCII character disables the fast path.
+    let overrides = Overrides::new().remove('\0'..='\0');
+
+    for options in [
+      Options::default(),
+      Options::default().retain_capitalization(),
+      Options::default().alphanumeric_only(),
+    ] {
+      let cured = super::cure_cow(&s, options).unwrap();
+      let expected = overrides.cure(&s, options).unwrap();
+
+      prop_assert_eq!(&*cured, expected.as_ref());
+    }
+  }
+
+  #[test]
+  fn matcher_set_consistency(s in "[hel1|3o0 _.\\-/!xy4ab]{0,24}") {
+    let words = ["hello", "oh yeah", "hell", "lo", "e", "b0b", "a-b", "yy"];
+    let set = MatcherSet::new(words);
+    let cured = super::cure!(&s).unwrap();
+
+    prop_assert_eq!(set.find(&cured), cured.find_multiple(words));
+    prop_assert_eq!(set.matches(&cured), cured.find_multiple_indexed(words));
+    prop_assert_eq!(set.is_match(&cured), !cured.find_multiple(words).is_empty());
+  }
+
+  #[test]
+  fn is_cured_ascii(bytes in prop::collection::vec(any::<u8>(), 0..64), retain_capitalization: bool) {
+    prop_assert_eq!(
+      super::util::is_cured_ascii(&bytes, retain_capitalization),
+      bytes
+        .iter()
+        .all(|&byte| matches!(byte, 10..=13 | 32..=126) && (retain_capitalization || !byte.is_ascii_uppercase()))
+    );
+  }
 }
 
 fn assert_matches(input: &str, find: &str, expected: Range<usize>, options: Options) {
@@ -59,6 +112,7 @@ fn leetspeak() {
   assert_matches("|-|3|aI_0", "helalo", 0..9, default_options);
   assert_matches("|-|3|_|_0", "he|_lo", 0..9, default_options);
   assert_matches("|--|3e33|__|_I_I_0()O[]", "hello", 0..23, default_options);
+  assert_matches("wow |-|3|_I_0", "hello", 4..13, default_options);
 
   assert_no_matches("|-|3|_|_0", "he+lo", default_options);
 
@@ -215,6 +269,1315 @@ fn censor() {
   assert_eq!(cured2, "******** this is a ****");
 }
 
+fn assert_original_ranges(input: &str, find: &str, expected: &[Range<usize>], options: Options) {
+  let cured = super::cure_mapped(input, options).unwrap();
+  let mat = cured.find(find).next().unwrap();
+
+  assert_eq!(cured.original_ranges(mat), expected);
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn original_ranges() {
+  let default_options = Options::default();
+
+  assert_original_ranges("hello", "hello", &[0..5], default_options);
+  assert_original_ranges("wow ＨＥＬＬＯ!", "hello", &[4..19], default_options);
+  assert_original_ranges("-h\u{0}ello-", "hello", &[1..7], default_options);
+
+  // combining diacritics belong to the character they are attached to.
+  assert_original_ranges("he\u{301}\u{302}llo wow", "hello", &[0..9], default_options);
+
+  // string translations are mapped as a whole.
+  assert_original_ranges("a🆐b", "adj", &[0..5], default_options);
+
+  #[cfg(feature = "options")]
+  {
+    let rtl_options = default_options.retain_hebrew();
+
+    // cures to "abc גבא", where "c ג" is not contiguous in the original input.
+    assert_original_ranges("abc אבג", "c ג", &[2..4, 8..10], rtl_options);
+    assert_original_ranges("abc אבג", "גב", &[6..10], rtl_options);
+
+    assert_original_ranges(
+      "wow ＨＥＬＬＯ!",
+      "hello",
+      &[4..19],
+      default_options.disable_bidi(),
+    );
+  }
+
+  let cured = super::cure_mapped("\u{0}abc", default_options).unwrap();
+
+  assert_eq!(cured.original(), "\u{0}abc");
+  assert!(cured.original_ranges(1..1).is_empty());
+  assert_eq!(cured.original_ranges(0..1), [0..2]);
+  assert_eq!(cured.original_ranges(0..3), [0..4]);
+}
+
+#[test]
+fn censor_original() {
+  let cured = super::cure_mapped("Wow, ＨＥ\u{301}ＬＬＯ there 👋", Options::default()).unwrap();
+
+  assert_eq!(cured.censor_original("hello", '*'), "Wow, ***** there 👋");
+  assert_eq!(cured.replace_original("hello", "Hi"), "Wow, Hi there 👋");
+  assert_eq!(cured.censor_original("goodbye", '*'), cured.original());
+
+  let cured = super::cure_mapped("word Word this is a wörd", Options::default()).unwrap();
+
+  assert_eq!(
+    cured.censor_multiple_original(["word", "this"], '-'),
+    "---- ---- ---- is a ----"
+  );
+  assert_eq!(
+    cured.replace_multiple_original(["word", "this"], "X"),
+    "X X X is a X"
+  );
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn matcher_set() {
+  let words = ["hello", "oh yeah", "boob", "boobie", "shit"];
+  let set = MatcherSet::new(words);
+
+  for input in [
+    "wow hello wow heellllo!",
+    "ｈꡩ𝔏┕⊕𝚑ᅠΎ⫕ᣲ𑀜",
+    "labiaxfag.boob!!boobie.",
+    "hh-he  e eeell/l/lo//o",
+    " shhhiii/iiiiitttttt/ttttt ",
+    "|-|3|_I_0 and oh yeah",
+    "nothing to see here",
+  ] {
+    let cured = super::cure!(input).unwrap();
+
+    assert_eq!(set.find(&cured), cured.find_multiple(words), "{input}");
+    assert_eq!(set.is_match(&cured), !cured.find_multiple(words).is_empty());
+  }
+
+  // partial matches that overlap with leetspeak don't make the set diverge from find_multiple.
+  let cured = super::cure!("-|-|titty").unwrap();
+
+  assert_eq!(
+    MatcherSet::new(["titty"]).find(&cured),
+    cured.find_multiple(["titty"])
+  );
+  assert!(MatcherSet::new(["", "x"]).find(&cured).is_empty());
+}
+
+#[test]
+fn find_multiple_indexed() {
+  let words = ["hello", "oh yeah", "hell"];
+  let set = MatcherSet::new(words);
+
+  for input in ["ｈꡩ𝔏┕⊕𝚑ᅠΎ⫕ᣲ𑀜", "hello oh yeah hell", "h3ll0 wow", "nope"] {
+    let cured = super::cure!(input).unwrap();
+    let matches = cured.find_multiple_indexed(words);
+
+    assert_eq!(set.matches(&cured), matches, "{input}");
+
+    for mat in matches {
+      assert!(cured.find(words[mat.index]).any(|range| range == mat.range));
+    }
+  }
+
+  let cured = super::cure!("hello, oh yeah! hell").unwrap();
+
+  assert_eq!(
+    cured.find_multiple_indexed(words),
+    [
+      Match {
+        index: 0,
+        range: 0..5
+      },
+      Match {
+        index: 2,
+        range: 0..4
+      },
+      Match {
+        index: 1,
+        range: 7..14
+      },
+      Match {
+        index: 2,
+        range: 16..20
+      },
+    ]
+  );
+}
+
+fn assert_explained(input: &str, options: Options, overrides: &Overrides) -> Vec<CureSource> {
+  let explanation = overrides.cure_explained(input, options).unwrap();
+  let cured = overrides.cure(input, options).unwrap();
+
+  assert_eq!(explanation.cured.as_ref(), cured.as_ref(), "{input}");
+  assert_eq!(
+    explanation
+      .steps
+      .iter()
+      .map(|step| step.character)
+      .collect::<String>(),
+    input
+  );
+
+  for step in &explanation.steps {
+    assert_eq!(&input[step.original.clone()], step.character.to_string());
+    assert_eq!(
+      &cured[step.cured.clone()],
+      Cow::<'static, str>::from(step.translation.clone())
+    );
+  }
+
+  explanation
+    .steps
+    .into_iter()
+    .map(|step| step.source)
+    .collect()
+}
+
+#[test]
+fn cure_explained() {
+  let options = Options::default();
+  let overrides = Overrides::new().character('ꙮ'..='ꙮ', 'o');
+
+  assert_eq!(
+    assert_explained("Ｈi\u{3000}\u{7}\u{e000}ꙮ", options, &overrides),
+    [
+      CureSource::MainTable,
+      CureSource::Ascii,
+      CureSource::Whitespace,
+      CureSource::Removed,
+      CureSource::Removed,
+      CureSource::Override,
+    ]
+  );
+
+  let explanation = super::cure_explained("abc אבג", options).unwrap();
+
+  assert_eq!(
+    explanation
+      .steps
+      .iter()
+      .map(|step| step.reversed)
+      .collect::<Vec<_>>(),
+    [false, false, false, false, true, true, true]
+  );
+
+  assert_eq!(explanation.steps[4].cured, 6..7);
+
+  assert_eq!(
+    assert_explained("a\u{202e}b", options, &overrides),
+    [
+      CureSource::Ascii,
+      CureSource::BidiControl,
+      CureSource::Ascii
+    ]
+  );
+
+  let step = &overrides
+    .cure_explained("a\u{202e}b", options)
+    .unwrap()
+    .steps[1];
+
+  assert_eq!(step.translation, Translation::Character('\u{202e}'));
+  assert_eq!(step.cured, 1..4);
+
+  #[cfg(feature = "options")]
+  {
+    assert_eq!(
+      assert_explained("a\u{202e}b", options.disable_bidi(), &overrides),
+      [
+        CureSource::Ascii,
+        CureSource::BidiControl,
+        CureSource::Ascii
+      ]
+    );
+    assert!(
+      overrides
+        .cure_explained("a\u{202e}b", options.disable_bidi())
+        .unwrap()
+        .steps[1]
+        .cured
+        .is_empty()
+    );
+
+    assert_eq!(
+      assert_explained("ａ!ꙮ\u{3042}", options.alphanumeric_only(), &overrides),
+      [
+        CureSource::MainTable,
+        CureSource::Stripped,
+        CureSource::Override,
+        CureSource::Stripped,
+      ]
+    );
+  }
+
+  assert_explained(
+    "Hello, \u{2068}\u{202E}world\u{202C}\u{2069}!",
+    options,
+    &overrides,
+  );
+  assert_explained("\u{05D0}ב(גד[&ef].)gh", options, &overrides);
+}
+
+#[test]
+fn script() {
+  assert_eq!(Script::of('a'), Script::Latin);
+  assert_eq!(Script::of('Ｚ'), Script::Latin);
+  assert_eq!(Script::of('ω'), Script::Greek);
+  assert_eq!(Script::of('ж'), Script::Cyrillic);
+  assert_eq!(Script::of('א'), Script::Hebrew);
+  assert_eq!(Script::of('漢'), Script::Han);
+  assert_eq!(Script::of('Ꮧ'), Script::Cherokee);
+  assert_eq!(Script::of('\u{301}'), Script::Inherited);
+  assert_eq!(Script::of('1'), Script::Common);
+  assert_eq!(Script::of('𝐚'), Script::Common);
+  assert_eq!(Script::of('ࠀ'), Script::Other);
+  assert_eq!(Script::of('ㄅ'), Script::Bopomofo);
+  assert_eq!(Script::of('ਗ'), Script::Gurmukhi);
+  assert_eq!(Script::of('ー'), Script::Common);
+  assert_eq!(Script::of('\u{e000}'), Script::Other);
+  assert_eq!(Script::of('\u{378}'), Script::Other);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn cured_string_serde() {
+  use super::{CuredString, CuredStringSeed, roundtrip};
+  use serde::de::DeserializeSeed;
+
+  let cured: CuredString = serde_json::from_str(r#""Hello \"wörld\"""#).unwrap();
+
+  assert_eq!(cured.as_ref(), "hello \"world\"");
+
+  let cured: CuredString = serde_json::from_reader("\"vＥⓡ𝔂 𝔽𝕌Ňℕｙ\"".as_bytes()).unwrap();
+
+  assert_eq!(cured.as_ref(), "very funny");
+  assert!(serde_json::from_str::<CuredString>("42").is_err());
+
+  let seed = CuredStringSeed::new(Options::default().retain_capitalization());
+  let cured = seed
+    .deserialize(&mut serde_json::Deserializer::from_str(r#""Ｈello""#))
+    .unwrap();
+
+  assert_eq!(cured.as_ref(), "Hello");
+  assert_eq!(serde_json::to_string(&cured).unwrap(), r#""Hello""#);
+
+  let to_json = |cured: &CuredString| {
+    let mut output = vec![];
+
+    roundtrip::serialize(cured, &mut serde_json::Serializer::new(&mut output)).unwrap();
+
+    String::from_utf8(output).unwrap()
+  };
+
+  let from_json =
+    |json: &str| roundtrip::deserialize(&mut serde_json::Deserializer::from_str(json));
+
+  let cured = from_json(&to_json(
+    &seed
+      .deserialize(&mut serde_json::Deserializer::from_str(r#""ＨＥＬＬＯ""#))
+      .unwrap(),
+  ))
+  .unwrap();
+
+  assert_eq!(cured.as_ref(), "HELLO");
+
+  #[cfg(all(feature = "leetspeak", feature = "options"))]
+  {
+    let mut cured = super::cure!("|-|ello").unwrap();
+
+    assert_eq!(cured, "hello");
+
+    cured.disable_leetspeak(true);
+
+    let json = to_json(&cured);
+
+    assert_eq!(
+      json,
+      r#"{"string":"|-|ello","disable_leetspeak":true,"disable_alphabetical_leetspeak":false}"#
+    );
+
+    let cured = from_json(&json).unwrap();
+
+    assert_eq!(cured.as_ref(), "|-|ello");
+    assert_ne!(cured, "hello");
+
+    let cured = from_json(r#"["|-|ello", false, true]"#).unwrap();
+
+    assert_eq!(cured, "hello");
+    assert!(cured.disable_alphabetical_leetspeak);
+  }
+
+  assert_eq!(
+    from_json(r#"{"string":"ab\ncd"}"#).unwrap().as_ref(),
+    "ab\ncd"
+  );
+  assert!(from_json(r#"{"disable_leetspeak":true}"#).is_err());
+  assert!(from_json(r#"{"string":"a","bogus":1}"#).is_err());
+  assert!(from_json("[]").is_err());
+}
+
+// the bits of the options that are supported by this build, since round trips leave out the rest.
+#[cfg(feature = "options")]
+fn supported(options: Options) -> u32 {
+  Options::flags()
+    .filter(|flag| flag.is_enabled(options))
+    .fold(0, |bits, flag| bits | flag.options().0)
+}
+
+#[test]
+#[cfg(feature = "options")]
+fn options_from_str() {
+  let parse = |input: &str| input.parse::<Options>().map(|options| options.0);
+
+  assert_eq!(
+    Options::flags().len(),
+    if cfg!(feature = "leetspeak") { 27 } else { 25 }
+  );
+
+  for (idx, flag) in Options::flags().enumerate() {
+    assert!(usize::from(flag.index) >= idx);
+    assert_eq!(parse(flag.name).unwrap(), flag.options().0);
+    assert!(flag.is_enabled(Options::ALL));
+    assert!(!flag.is_enabled(Options::default()));
+    assert!(!flag.description.is_empty());
+  }
+
+  assert_eq!(parse("").unwrap(), 0);
+  assert_eq!(parse(" , ").unwrap(), 0);
+  assert_eq!(
+    parse("retain_capitalization,disable_bidi").unwrap(),
+    Options::default().retain_capitalization().disable_bidi().0
+  );
+  assert_eq!(
+    parse(" ascii_only ,retain_turkish, ").unwrap(),
+    Options::default().ascii_only().retain_turkish().0
+  );
+  assert_eq!(parse("all").unwrap(), Options::ALL.0);
+  assert_eq!(
+    parse("disable_leetspeak").is_ok(),
+    cfg!(feature = "leetspeak")
+  );
+  assert_eq!(
+    parse("pure_homoglyph,retain_capitalization").unwrap(),
+    Options::PURE_HOMOGLYPH.retain_capitalization().0
+  );
+  assert_eq!(
+    parse("retain_greek,RETAIN_CYRILLIC").unwrap_err().name(),
+    "RETAIN_CYRILLIC"
+  );
+  assert_eq!(
+    parse("retain_greek,,bogus").unwrap_err().to_string(),
+    "unknown option `bogus`"
+  );
+
+  assert_eq!(Options::default().to_string(), "");
+
+  for options in [
+    Options::default(),
+    Options::ALL,
+    Options::PURE_HOMOGLYPH,
+    Options::default().retain_emojis().alphanumeric_only(),
+  ] {
+    assert_eq!(parse(&options.to_string()).unwrap(), supported(options));
+  }
+}
+
+// a format that is not self-describing, which only supports a list of names just like bincode or postcard.
+#[cfg(all(feature = "options", feature = "serde"))]
+struct Compact<'a>(&'a [&'a str]);
+
+#[cfg(all(feature = "options", feature = "serde"))]
+impl<'de> serde::Deserializer<'de> for Compact<'de> {
+  type Error = serde::de::value::Error;
+
+  fn deserialize_any<V: serde::de::Visitor<'de>>(
+    self,
+    _visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    Err(serde::de::Error::custom("deserialize_any is not supported"))
+  }
+
+  fn deserialize_seq<V: serde::de::Visitor<'de>>(
+    self,
+    visitor: V,
+  ) -> Result<V::Value, Self::Error> {
+    visitor.visit_seq(serde::de::value::SeqDeserializer::new(
+      self.0.iter().copied(),
+    ))
+  }
+
+  fn is_human_readable(&self) -> bool {
+    false
+  }
+
+  serde::forward_to_deserialize_any! {
+    bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit unit_struct newtype_struct tuple tuple_struct map struct enum identifier ignored_any
+  }
+}
+
+#[test]
+#[cfg(all(feature = "options", feature = "serde"))]
+fn options_serde() {
+  use serde::Deserialize;
+
+  let from_json = |json: &str| serde_json::from_str::<Options>(json).map(|options| options.0);
+
+  assert_eq!(from_json("[]").unwrap(), 0);
+  assert_eq!(
+    from_json(r#"["retain_greek", "ascii_only"]"#).unwrap(),
+    Options::default().retain_greek().ascii_only().0
+  );
+  assert_eq!(
+    from_json(r#""pure_homoglyph""#).unwrap(),
+    Options::PURE_HOMOGLYPH.0
+  );
+  assert_eq!(from_json(r#"["all"]"#).unwrap(), Options::ALL.0);
+  assert_eq!(
+    from_json(r#"{"retain_capitalization": true, "retain_emojis": false}"#).unwrap(),
+    Options::default().retain_capitalization().0
+  );
+  assert_eq!(
+    from_json(r#"{"pure_homoglyph": true, "retain_greek": false}"#).unwrap(),
+    Options::PURE_HOMOGLYPH.0 & !Options::default().retain_greek().0
+  );
+
+  let err = from_json(r#"["retain_greek", "retain_klingon"]"#)
+    .unwrap_err()
+    .to_string();
+
+  assert!(err.contains("unknown variant `retain_klingon`"), "{err}");
+  assert!(err.contains("`retain_greek`"), "{err}");
+  assert!(err.contains("`all`"), "{err}");
+  assert!(err.contains("`pure_homoglyph`"), "{err}");
+  assert!(from_json("1").is_err());
+  assert!(from_json(r#"[["ascii_only"]]"#).is_err());
+  assert!(from_json(r#"["ascii_only", {"retain_greek": true}]"#).is_err());
+  assert!(from_json(r#"{"retain_greek": 1}"#).is_err());
+
+  let err = from_json(r#"{"retain_greek": true, "ascii_only": true, "retain_greek": false}"#)
+    .unwrap_err()
+    .to_string();
+
+  assert!(err.contains("duplicate field `retain_greek`"), "{err}");
+
+  assert_eq!(
+    serde_json::to_string(&Options::default().retain_capitalization().retain_turkish()).unwrap(),
+    r#"["retain_capitalization","retain_turkish"]"#
+  );
+
+  for options in [
+    Options::default(),
+    Options::ALL,
+    Options::PURE_HOMOGLYPH,
+    Options::default().disable_bidi().alphanumeric_only(),
+  ] {
+    let json = serde_json::to_string(&options).unwrap();
+
+    assert_eq!(from_json(&json).unwrap(), supported(options));
+  }
+
+  assert_eq!(
+    Options::deserialize(Compact(&["retain_greek", "ascii_only"]))
+      .unwrap()
+      .0,
+    Options::default().retain_greek().ascii_only().0
+  );
+}
+
+#[test]
+#[allow(clippy::mutable_key_type)]
+fn canonical_key() {
+  let key = |input: &str| super::cure!(input).unwrap().canonical_key();
+
+  for (a, b) in [
+    ("hello", "h3ll0"),
+    ("hello", "he110"),
+    ("hello", "heeelllo"),
+    ("hello", "h.e.l.l.o"),
+    ("hello", "h-e-l-l-o"),
+    ("hello", "|-|ello"),
+    ("admin", "/\\dm!n"),
+    ("paypal", "p4yp41"),
+    ("hello world", "helloworld"),
+    ("b", "13"),
+    ("w", "vv"),
+    ("w", "v.v"),
+    ("p", "lo"),
+    ("bob", "6ob"),
+    // "hello" is equal to "help" since "lo" is a leetspeak spelling of "p".
+    ("hello", "help"),
+  ] {
+    assert_eq!(key(a), key(b), "{a} and {b}");
+  }
+
+  for (a, b) in [("hello", "goodbye"), ("tokyo", "tokyo東京")] {
+    assert_ne!(key(a), key(b), "{a} and {b}");
+  }
+
+  for a in (0u8..128).map(char::from) {
+    for b in (0u8..128).map(char::from) {
+      if super::is_similar(a, b) {
+        assert_eq!(
+          super::similar::representative(a),
+          super::similar::representative(b),
+          "{a:?} and {b:?}"
+        );
+      }
+    }
+  }
+
+  let mut cured = super::cure!("h.e.l.l.o").unwrap();
+
+  #[cfg(feature = "separators")]
+  cured.separators(Separators::STRICT);
+
+  assert_ne!(cured.canonical_key(), key("hello"));
+
+  #[cfg(all(feature = "leetspeak", feature = "options"))]
+  {
+    let mut cured = super::cure!("|-|ello").unwrap();
+
+    cured.disable_leetspeak(true);
+
+    assert_ne!(cured.canonical_key(), key("hello"));
+  }
+
+  let mut set = HashSet::new();
+
+  for input in ["hello", "h3ll0", "|-|ello", "world", "w0rld", "goodbye"] {
+    set.insert(SimilarKey::new(super::cure!(input).unwrap()));
+  }
+
+  assert_eq!(set.len(), 3);
+  assert!(set.contains(&SimilarKey::from(super::cure!("HELLO").unwrap())));
+  assert!(!set.contains(&SimilarKey::from(super::cure!("hat").unwrap())));
+
+  // unlike comparisons, repeated characters are removed from both sides.
+  assert_ne!(super::cure!("helo").unwrap(), "hello");
+  assert_eq!(key("helo"), key("hello"));
+  assert!(set.contains(&SimilarKey::from(super::cure!("helo").unwrap())));
+}
+
+#[test]
+#[cfg(feature = "leetspeak")]
+fn similar_key_variants() {
+  let options = Options::default();
+
+  // keys can't be fully consistent with comparisons (see SimilarKey), but they are for these.
+  for target in [
+    "user", "test", "owner", "system", "root", "mod", "support", "w",
+  ] {
+    let key = SimilarKey::new(super::cure(target, options).unwrap());
+
+    for variant in super::variants(target, options)
+      .take(300)
+      .chain(super::variants(target, options).randomized(1).take(300))
+      .chain(super::variants(target, options).randomized(2).take(300))
+    {
+      assert_eq!(
+        SimilarKey::new(super::cure(&variant, options).unwrap()),
+        key,
+        "{variant:?} and {target:?}"
+      );
+    }
+  }
+}
+
+#[test]
+fn identifier_policy() {
+  let options = Options::default();
+
+  assert!(IdentifierPolicy::new().is_valid("", options).unwrap());
+  assert!(
+    IdentifierPolicy::default()
+      .is_valid("ᚠ\u{200b}\u{202e}a\u{301}", options)
+      .unwrap()
+  );
+
+  let policy = IdentifierPolicy::new()
+    .reserved(["admin"])
+    .reserved([String::from("root")])
+    .max_invisible(1)
+    .max_combining(2)
+    .forbid_bidi_overrides()
+    .min_length(4)
+    .restriction_level(RestrictionLevel::ModeratelyRestrictive);
+
+  assert!(policy.is_valid("café_user", options).unwrap());
+  assert!(policy.is_valid("user\u{200b}name", options).unwrap());
+  assert!(policy.is_valid("shalomשלום", options).unwrap());
+
+  assert_eq!(
+    policy.validate("ℝ𝕆𝕆𝕋", options).unwrap(),
+    [Violation::Reserved(String::from("root"))]
+  );
+  assert_eq!(
+    policy.validate("a.d.m.1.n", options).unwrap(),
+    [Violation::Reserved(String::from("admin"))]
+  );
+  assert_eq!(
+    policy
+      .validate("u\u{200b}s\u{feff}e\u{2066}r", options)
+      .unwrap(),
+    [
+      Violation::TooManyInvisible { count: 3, max: 1 },
+      Violation::BidiOverride,
+    ]
+  );
+  assert_eq!(
+    policy
+      .validate("u\u{308}\u{337}\u{322}ser", options)
+      .unwrap(),
+    [Violation::TooManyCombining { count: 3, max: 2 }]
+  );
+  assert_eq!(
+    policy.validate(" a  b ", options).unwrap(),
+    [Violation::TooShort { length: 2, min: 4 }]
+  );
+  assert_eq!(
+    policy.validate("p\u{0430}ypal", options).unwrap(),
+    [Violation::MixedScript(RestrictionLevel::Unrestricted)]
+  );
+
+  let policy = IdentifierPolicy::new()
+    .max_combining(0)
+    .forbid_bidi_overrides();
+
+  for input in [
+    "hello",
+    "ｈ̸̢ė̷l͓͊l̸о",
+    "a\u{200d}b\u{fe0f}c\u{34f}",
+    "\u{202a}a\u{202c}",
+    "\u{202d}abc",
+    "\u{2067}שלום\u{2069}",
+    "\u{200e}a\u{200f}",
+    "e\u{301}\u{202e}abc",
+  ] {
+    let report = super::analyze(input, options).unwrap();
+    let violations = policy.validate(input, options).unwrap();
+
+    assert_eq!(
+      violations.contains(&Violation::BidiOverride),
+      report.has_bidi_override
+    );
+    assert_eq!(
+      violations.contains(&Violation::TooManyCombining {
+        count: report.combining_marks,
+        max: 0,
+      }),
+      report.combining_marks > 0
+    );
+  }
+}
+
+#[test]
+fn restriction_level() {
+  assert_eq!(RestrictionLevel::of(""), RestrictionLevel::AsciiOnly);
+  assert_eq!(
+    RestrictionLevel::of("admin_42"),
+    RestrictionLevel::AsciiOnly
+  );
+  assert_eq!(RestrictionLevel::of("café"), RestrictionLevel::SingleScript);
+  assert_eq!(
+    RestrictionLevel::of("ελληνικά"),
+    RestrictionLevel::SingleScript
+  );
+  assert_eq!(
+    RestrictionLevel::of("東京とトウキョウ"),
+    RestrictionLevel::SingleScript
+  );
+  assert_eq!(
+    RestrictionLevel::of("서울市"),
+    RestrictionLevel::SingleScript
+  );
+  assert_eq!(
+    RestrictionLevel::of("tokyo東京とうきょう"),
+    RestrictionLevel::HighlyRestrictive
+  );
+  assert_eq!(
+    RestrictionLevel::of("seoul서울"),
+    RestrictionLevel::HighlyRestrictive
+  );
+  assert_eq!(
+    RestrictionLevel::of("shalomשלום"),
+    RestrictionLevel::ModeratelyRestrictive
+  );
+  assert_eq!(
+    RestrictionLevel::of("らーめん"),
+    RestrictionLevel::SingleScript
+  );
+  assert_eq!(
+    RestrictionLevel::of("ㄅㄆ漢字"),
+    RestrictionLevel::SingleScript
+  );
+  assert_eq!(
+    RestrictionLevel::of("ramenラーメン"),
+    RestrictionLevel::HighlyRestrictive
+  );
+  assert_eq!(
+    RestrictionLevel::of("namasteनमस्ते।"),
+    RestrictionLevel::ModeratelyRestrictive
+  );
+  assert_eq!(
+    RestrictionLevel::of("p\u{0430}ypal"),
+    RestrictionLevel::Unrestricted
+  );
+  assert_eq!(
+    RestrictionLevel::of("αlpha"),
+    RestrictionLevel::Unrestricted
+  );
+  assert_eq!(
+    RestrictionLevel::of("שלוםсалам"),
+    RestrictionLevel::Unrestricted
+  );
+  assert_eq!(RestrictionLevel::of("ᚠᚢᚦ"), RestrictionLevel::Unrestricted);
+  assert!(RestrictionLevel::AsciiOnly < RestrictionLevel::Unrestricted);
+
+  assert_eq!(
+    super::scripts("ｈｅ\u{301}ꙮ1ɑ"),
+    [Script::Latin, Script::Cyrillic]
+  );
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn analyze() {
+  let options = Options::default();
+  let report = super::analyze("hello", options).unwrap();
+
+  assert_eq!(report.total, 5);
+  assert_eq!(report.unchanged, 5);
+  assert_eq!(report.score(), 0.0);
+  assert_eq!(report.zalgo_density(), 0.0);
+  assert_eq!(report.scripts, [Script::Latin]);
+  assert!(!report.is_mixed_script());
+
+  let report = super::analyze("ｈｅ\u{301}\u{302}ꙮ\u{202e}ɑ\u{7}", options).unwrap();
+
+  assert_eq!(report.total, 8);
+  assert_eq!(report.translated, 3);
+  assert_eq!(report.removed, 3);
+  assert_eq!(report.unchanged, 2);
+  assert_eq!(report.combining_marks, 2);
+  assert_eq!(report.zalgo_density(), 2.0 / 6.0);
+  assert_eq!(report.bidi_controls, 1);
+  assert!(report.has_bidi_override);
+  assert_eq!(report.scripts, [Script::Latin, Script::Cyrillic]);
+
+  assert_eq!(super::analyze("", options).unwrap().score(), 0.0);
+}
+
+#[test]
+#[cfg(feature = "lookup_table")]
+fn lookup_table() {
+  use super::codepoints::{
+    CASE_SENSITIVE_CODEPOINTS_COUNT, CASE_SENSITIVE_CODEPOINTS_OFFSET, CODEPOINTS_COUNT, Codepoint,
+  };
+
+  for (offset, end) in [
+    (6, CODEPOINTS_COUNT.into()),
+    (
+      CASE_SENSITIVE_CODEPOINTS_OFFSET.into(),
+      CASE_SENSITIVE_CODEPOINTS_COUNT.into(),
+    ),
+  ] {
+    for code in 0..=0x11_0000 {
+      assert_eq!(
+        Codepoint::search(code, offset, end),
+        Codepoint::binary_search(code, offset, end)
+      );
+    }
+  }
+}
+
+#[test]
+fn similar() {
+  use super::{
+    codepoints::CODEPOINTS,
+    similar::{SIMILAR_END, SIMILAR_START},
+  };
+
+  // the linear scan used before the similarity bitset was precomputed.
+  let scan = |a: u8, b: u8| {
+    let mut id = 0;
+
+    for offset in SIMILAR_START..SIMILAR_END {
+      let cur = CODEPOINTS.at(offset as _);
+      let sim = cur & 0x7f;
+
+      if sim == a {
+        id |= 1;
+      } else if sim == b {
+        id |= 2;
+      }
+
+      if id == 3 {
+        return true;
+      } else if cur >= 0x80 {
+        id = 0;
+      }
+    }
+
+    false
+  };
+
+  for a in 0..0x80u8 {
+    for b in 0..0x80u8 {
+      let (a_lowercase, b_lowercase) = (a.to_ascii_lowercase(), b.to_ascii_lowercase());
+
+      assert_eq!(
+        super::is_similar(a as _, b as _),
+        a_lowercase == b_lowercase || scan(a_lowercase, b_lowercase),
+      );
+
+      assert_eq!(
+        super::is_similar(a as _, b as _),
+        super::similar_chars(a as _).any(|c| c == b_lowercase as char),
+      );
+    }
+  }
+
+  assert!(super::is_similar('é', 'É'));
+  assert!(!super::is_similar('é', 'e'));
+
+  let groups: Vec<_> = super::similar_groups().collect();
+
+  for group in &groups {
+    for &a in group {
+      for &b in group {
+        assert!(super::is_similar(a, b));
+      }
+    }
+  }
+
+  assert_eq!(
+    groups.iter().map(Vec::len).sum::<usize>(),
+    usize::from(SIMILAR_END - SIMILAR_START)
+  );
+}
+
+#[test]
+fn reverse_cure() {
+  let options = Options::default();
+  let ranges: Vec<_> = super::reverse_cure("lj", options).collect();
+
+  assert!(ranges.iter().any(|range| range.contains(&'ǉ')));
+  assert!(ranges.iter().any(|range| range.contains(&'Ǉ')));
+
+  for range in &ranges {
+    for character in range.clone() {
+      assert!(matches!(
+        super::cure_char(character, options),
+        Translation::String(string) if string.as_ref() == "lj"
+      ));
+    }
+  }
+
+  for window in ranges.windows(2) {
+    assert!(u32::from(*window[0].end()) + 1 < u32::from(*window[1].start()));
+  }
+
+  let ranges: Vec<_> = super::reverse_cure("A", options.retain_capitalization()).collect();
+
+  assert!(ranges.iter().any(|range| range.contains(&'Ａ')));
+  assert!(!ranges.iter().any(|range| range.contains(&'a')));
+  assert_eq!(super::reverse_cure("A", options).count(), 0);
+
+  // the case exceptions are derived at build time, so compare them against every character.
+  assert!(super::reverse::case_exceptions().any(|code| code == 0x212a));
+
+  let targets = ["a", "k", "i", "s", "ss", "dz", "lj", "ω", "å"];
+  let mut expected = vec![vec![]; targets.len()];
+
+  for character in '\0'..=char::MAX {
+    let translation = Cow::from(super::cure_char(character, options));
+
+    if let Some(idx) = targets.iter().position(|&target| translation == target) {
+      expected[idx].push(character);
+    }
+  }
+
+  for (target, expected) in targets.into_iter().zip(expected) {
+    assert_eq!(
+      super::reverse_cure(target, options)
+        .flatten()
+        .collect::<Vec<_>>(),
+      expected,
+      "{target}"
+    );
+  }
+}
+
+#[test]
+fn variants() {
+  let options = Options::default();
+
+  for target in ["admin", "lj", "x"] {
+    let variants: Vec<_> = super::variants(target, options)
+      .take(200)
+      .chain(super::variants(target, options).randomized(1).take(200))
+      .collect();
+
+    assert!(!variants.is_empty());
+
+    for variant in variants {
+      assert_ne!(variant, target);
+      assert_eq!(super::cure(&variant, options).unwrap(), target);
+    }
+  }
+
+  #[cfg(feature = "leetspeak")]
+  {
+    for ((letter, forms), regex) in (b'a'..=b'z')
+      .zip(super::variants::LEETSPEAK)
+      .zip(super::leetspeak::REGEXES.iter())
+    {
+      for form in forms {
+        assert!(regex.as_ref().unwrap().is_match(form.as_bytes()));
+        assert_eq!(
+          super::cure(form, options).unwrap(),
+          String::from(char::from(letter))
+        );
+      }
+    }
+
+    assert!(super::variants("w", options).any(|variant| variant == "\\/\\/"));
+    assert!(!super::variants("w", options.disable_leetspeak()).any(|variant| variant == "\\/\\/"));
+  }
+
+  #[cfg(feature = "separators")]
+  assert!(super::variants("hi", options).any(|variant| variant == "h.i"));
+
+  assert_eq!(super::variants("", options).count(), 0);
+  assert_eq!(super::variants("", options).randomized(1).count(), 0);
+  assert_eq!(super::variants("éa", options).count(), 0);
+  assert_eq!(super::variants("éa", options).randomized(1).count(), 0);
+
+  // the right-to-left override reverses whatever follows it, so no combination cures back to this.
+  assert_eq!(
+    super::variants("\u{202e}12", options).randomized(1).next(),
+    None
+  );
+}
+
+#[test]
+fn cure_cow() {
+  let input = String::from("wow heellllo");
+  let cured = super::cure_cow(&input, Options::default()).unwrap();
+
+  assert_eq!(cured.find("hello").next(), Some(4..12));
+  assert!(matches!(Cow::from(cured.clone()), Cow::Borrowed(_)));
+
+  let owned: super::CuredString = cured.into_owned();
+
+  drop(input);
+
+  assert_eq!(owned, "wow hello");
+}
+
+#[test]
+fn curer() {
+  let inputs = [
+    "vＥⓡ𝔂 𝔽𝕌Ňℕｙ ţ乇𝕏𝓣",
+    "abc\nابج\r\n\u{05D0}בג abc",
+    "",
+    "\u{05D0}ב(גד[&ef].)gh",
+    "Hello, \u{2068}\u{202E}world\u{202C}\u{2069}!",
+  ];
+
+  for options in [
+    Options::default(),
+    Options::default().retain_hebrew().retain_arabic(),
+    Options::default().disable_bidi(),
+  ] {
+    let mut curer = Curer::new(options);
+    let mut output = String::from("prefix");
+    let mut expected = output.clone();
+
+    for input in inputs {
+      assert_eq!(
+        curer.cure(input).unwrap().as_ref(),
+        super::cure(input, options).unwrap().as_ref()
+      );
+
+      curer.cure_into(input, &mut output).unwrap();
+      expected.push_str(&super::cure(input, options).unwrap());
+    }
+
+    assert_eq!(output, expected);
+  }
+}
+
+// a reader that is interrupted before every read, which std's BufRead implementations retry.
+struct Interrupting<'a> {
+  input: &'a [u8],
+  interrupted: bool,
+}
+
+impl Read for Interrupting<'_> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    self.interrupted = !self.interrupted;
+
+    if self.interrupted {
+      return Err(io::ErrorKind::Interrupted.into());
+    }
+
+    self.input.read(buf)
+  }
+}
+
+#[test]
+fn stream() {
+  let options = Options::default();
+
+  for input in [
+    "vＥⓡ𝔂 𝔽𝕌Ňℕｙ\nţ乇𝕏𝓣",
+    "abc\nابج\r\n\u{05D0}בג abc\u{2029}123 \u{05D0}\u{05D1}\u{05D2}",
+    "Hello, \u{2068}\u{202E}world\u{202C}\u{2069}!\n\u{1c}\n",
+  ] {
+    let expected = super::cure(input, options).unwrap().to_string();
+
+    for size in 1..=input.len() {
+      let mut curer = StreamCurer::new(options);
+      let mut output = String::new();
+
+      for chunk in input.as_bytes().chunks(size) {
+        curer.push(chunk, &mut output).unwrap();
+      }
+
+      curer.finish(&mut output).unwrap();
+
+      assert_eq!(output, expected);
+    }
+
+    let mut output = String::new();
+
+    CuredReader::new(input.as_bytes(), options)
+      .read_to_string(&mut output)
+      .unwrap();
+
+    assert_eq!(output, expected);
+  }
+
+  let mut curer = StreamCurer::new(options);
+  let mut output = String::new();
+
+  curer.push(b"a\xffb\n\xe2\x82", &mut output).unwrap();
+
+  assert_eq!(
+    output,
+    super::cure("a\u{fffd}b\n", options).unwrap().to_string()
+  );
+
+  curer.finish(&mut output).unwrap();
+
+  assert_eq!(
+    output,
+    super::cure("a\u{fffd}b\n\u{fffd}", options)
+      .unwrap()
+      .to_string()
+  );
+
+  let mut reader = CuredReader::new(
+    Interrupting {
+      input: "ＨＥＬＬＯ\nwｏrld".as_bytes(),
+      interrupted: false,
+    },
+    options,
+  );
+
+  assert_eq!(reader.fill_buf().unwrap(), b"hello\n");
+  reader.consume(6);
+  assert_eq!(reader.fill_buf().unwrap(), b"world");
+}
+
+#[test]
+#[cfg(feature = "leetspeak")]
+fn leetspeak_rules() {
+  let set = MatcherSet::new(["hello", "cool", "2ж"]);
+  let mut cured = super::cure!("|-|ello %00l two>|<").unwrap();
+
+  assert!(cured.contains("hello"));
+  assert!(!cured.contains("cool"));
+  assert_eq!(set.find(&cured).len(), 1);
+
+  cured.leetspeak_rules(
+    LeetspeakRules::new()
+      .add('c', "%")
+      .unwrap()
+      .add('2', "two")
+      .unwrap()
+      .add('Ж', ">\\|<")
+      .unwrap(),
+  );
+
+  assert!(cured.contains("hello"));
+  assert!(cured.contains("cool"));
+  assert!(cured.contains("2ж"));
+  assert_eq!(set.find(&cured).len(), 3);
+
+  cured.leetspeak_rules(LeetspeakRules::empty().add('c', "%").unwrap());
+
+  assert!(!cured.contains("hello"));
+  assert!(cured.contains("cool"));
+  assert_eq!(set.find(&cured).len(), 1);
+
+  #[cfg(feature = "options")]
+  {
+    cured.disable_leetspeak(true);
+
+    assert!(!cured.contains("cool"));
+    assert!(!set.is_match(&cured));
+  }
+
+  // custom rules don't affect hashing.
+  let state = RandomState::new();
+  let plain = super::cure!("cool").unwrap();
+  let mut custom = plain.clone();
+
+  custom.leetspeak_rules(LeetspeakRules::empty());
+
+  assert_eq!(state.hash_one(&plain), state.hash_one(&custom));
+
+  assert!(LeetspeakRules::new().add('a', "(").is_err());
+  assert_eq!(
+    LeetspeakRules::new().add('a', "4").unwrap(),
+    LeetspeakRules::new().add('A', "4").unwrap()
+  );
+}
+
+#[test]
+#[allow(clippy::single_range_in_vec_init)]
+fn overrides() {
+  let overrides = Overrides::new()
+    .character('a'..='z', 'x')
+    .string('c'..'e', "see")
+    .remove('d'..='d')
+    .character('ꙮ'..='ꙮ', 'o')
+    .character('ω'..='ω', 'w')
+    .character('!'..='!', '?')
+    .character('\u{e000}'..='\u{e000}', 'Q')
+    .string('\u{a0}'..='\u{a0}', "NB");
+
+  let options = Options::default();
+
+  assert_eq!(
+    overrides.cure_char('b', options),
+    Translation::Character('x')
+  );
+  assert_eq!(
+    overrides.cure_char('B', options),
+    Translation::Character('x')
+  );
+  assert_eq!(overrides.cure_char('d', options), Translation::None);
+  assert_eq!(
+    overrides.cure_char('e', options),
+    Translation::Character('x')
+  );
+  assert_eq!(
+    overrides.cure_char('ω', options),
+    Translation::Character('w')
+  );
+  assert_eq!(
+    overrides.cure_char('Ω', options),
+    Translation::Character('w')
+  );
+  assert_eq!(
+    overrides.cure_char('1', options),
+    Translation::Character('1')
+  );
+  assert_eq!(overrides.cure("acde", options).unwrap(), "xseex");
+  assert_eq!(
+    overrides.cure_char('\u{e000}', options),
+    Translation::Character('q')
+  );
+  assert_eq!(
+    overrides
+      .cure("1\u{e000}2\u{a0}3", options)
+      .unwrap()
+      .as_ref(),
+    "1q2nb3"
+  );
+  assert_eq!(
+    super::cure("1\u{e000}2\u{a0}3", options).unwrap().as_ref(),
+    "12 3"
+  );
+
+  let mapped = overrides.cure_mapped("wow ꙮꙮ", options).unwrap();
+
+  assert_eq!(mapped.original_ranges(4..6), [4..10]);
+
+  // overrides of lowercase characters are applied to their uppercase forms in every pass.
+  assert!(overrides.contains('Ω' as _));
+  assert!(!overrides.contains('1' as _));
+  assert_eq!(
+    assert_explained("ΩωΩ", options, &overrides),
+    [CureSource::Override; 3]
+  );
+
+  #[cfg(feature = "options")]
+  {
+    assert_eq!(
+      overrides.cure_char('ω', options.retain_greek()),
+      Translation::Character('ω')
+    );
+    assert_eq!(
+      overrides.cure_char('ꙮ', options.retain_capitalization()),
+      Translation::Character('o')
+    );
+    assert_eq!(
+      overrides.cure_char('B', options.retain_capitalization()),
+      Translation::Character('X')
+    );
+    assert_eq!(
+      overrides.cure_char('!', options.alphanumeric_only()),
+      Translation::None
+    );
+  }
+}
+
+#[test]
+#[cfg(feature = "separators")]
+#[allow(clippy::single_range_in_vec_init)]
+fn separators() {
+  let mut cured = super::cure!("h.e.l.l.o h..e l l o h.-e.-l.-l.-o").unwrap();
+  let set = MatcherSet::new(["hello"]);
+
+  assert_eq!(cured.find("hello").count(), 2);
+
+  cured.separators(Separators::STRICT);
+  assert_eq!(cured.find("hello").count(), 0);
+  assert!(!set.is_match(&cured));
+
+  cured.separators(Separators::STRICT.punctuation());
+  assert_eq!(cured.find("hello").collect::<Vec<_>>(), [0..9]);
+  assert_eq!(set.find(&cured), [0..9]);
+
+  cured.separators(Separators::STRICT.punctuation().max_run_length(1));
+  assert_eq!(cured.find("hello").collect::<Vec<_>>(), [0..9]);
+
+  cured.separators(Separators::STRICT.punctuation().mixed());
+  assert_eq!(cured.find("hello").collect::<Vec<_>>(), [0..9, 21..34]);
+  assert_eq!(set.find(&cured), [0..9, 21..34]);
+
+  cured.separators(Separators::STRICT.whitespace().punctuation());
+  assert_eq!(cured.find("hello").collect::<Vec<_>>(), [0..9, 10..20]);
+  assert_eq!(set.find(&cured), [0..9, 10..20]);
+
+  let mut cured = super::cure!("h1e1l1l1o").unwrap();
+
+  assert!(cured.contains("hello"));
+
+  cured.separators(Separators::STRICT.punctuation());
+  assert!(!cured.contains("hello"));
+
+  cured.separators(Separators::STRICT.digits());
+  assert!(cured.contains("hello"));
+}
+
 #[test]
 fn bidi_class() {
   assert_eq!(Class::new(0x0000), Some(Class::BN));
@@ -432,3 +1795,82 @@ fn reorder() {
   test_reorder("\u{05D0}(ב)ג.", ".ג)ב(א");
   test_reorder("\u{05D0}ב(גד[&ef].)gh", "gh).]ef&[דג(בא");
 }
+
+#[test]
+#[cfg(feature = "bidi")]
+fn bidi_info() {
+  for (input, expected) in [
+    ("abc\ndef", "abc\ndef"),
+    ("abc אבג", "abc גבא"),
+    ("123 \u{05D0}\u{05D1}\u{05D2}", "גבא 123"),
+    ("\u{05D0}(ב)ג.", ".ג)ב(א"),
+    ("\u{05D0}\u{a0}\u{200b}ב", "ב\u{200b}\u{a0}א"),
+  ] {
+    let info = BidiInfo::new(input).unwrap();
+
+    assert_eq!(info.text(), input);
+    assert_eq!(info.levels().len(), input.len());
+    assert_eq!(info.reordered().unwrap(), expected);
+  }
+
+  let info = BidiInfo::new("אבג abc\nabc").unwrap();
+  let paragraphs = info.paragraphs();
+
+  assert_eq!(paragraphs.len(), 2);
+  assert_eq!(paragraphs[0].range(), 0..11);
+  assert!(paragraphs[0].level().is_rtl());
+  assert_eq!(paragraphs[1].range(), 11..14);
+  assert!(paragraphs[1].level().is_ltr());
+
+  assert_eq!(info.levels()[0].number(), 1);
+  assert_eq!(info.levels()[1].number(), 1);
+  assert_eq!(info.levels()[7].number(), 2);
+  assert_eq!(info.levels()[11].number(), 0);
+
+  let runs: Vec<_> = info
+    .visual_runs(&paragraphs[0])
+    .unwrap()
+    .into_iter()
+    .map(|(range, level)| (range, level.number()))
+    .collect();
+
+  assert_eq!(runs, [(10..11, 1), (7..10, 2), (0..7, 1)]);
+
+  assert!(BidiInfo::new("").unwrap().paragraphs().is_empty());
+}
+
+#[test]
+#[cfg(feature = "confusables")]
+fn confusables() {
+  assert_eq!(super::skeleton(""), "");
+  assert_eq!(super::skeleton("paypal"), "paypal");
+  assert_eq!(super::skeleton("rn"), "rn");
+  assert_eq!(super::skeleton("\u{212b}"), "A\u{30a}");
+  assert_eq!(
+    super::skeleton("한"),
+    super::skeleton("\u{1112}\u{1161}\u{11ab}")
+  );
+  assert_eq!(
+    super::skeleton("a\u{301}\u{323}"),
+    super::skeleton("a\u{323}\u{301}")
+  );
+  assert_eq!(super::skeleton("ẹ́"), super::skeleton("e\u{323}\u{301}"));
+
+  assert_eq!(
+    super::are_confusable("ǉeto", "ljeto"),
+    Some(ConfusableKind::SingleScript)
+  );
+  assert_eq!(
+    super::are_confusable("paypal", "paypal"),
+    Some(ConfusableKind::SingleScript)
+  );
+  assert_eq!(
+    super::are_confusable("paypal", "p\u{0430}ypal"),
+    Some(ConfusableKind::MixedScript)
+  );
+  assert_eq!(
+    super::are_confusable("scope", "ѕсоре"),
+    Some(ConfusableKind::WholeScript)
+  );
+  assert_eq!(super::are_confusable("paypal", "PAYPAL"), None);
+}
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 // SPDX-FileCopyrightText: 2021-2026 null8626
 
+#[cfg(feature = "separators")]
+use super::Separators;
 #[cfg(feature = "options")]
 use super::util::is_alphanumeric;
 use super::{
@@ -52,6 +54,10 @@ impl Translation {
       disable_leetspeak,
       #[cfg(all(feature = "leetspeak", feature = "options"))]
       disable_alphabetical_leetspeak,
+      #[cfg(feature = "leetspeak")]
+      leetspeak_rules: None,
+      #[cfg(feature = "separators")]
+      separators: Separators::LENIENT,
     })
   }
 
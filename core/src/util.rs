@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 // SPDX-FileCopyrightText: 2021-2026 null8626
 
+use super::Script;
 use std::{ops::Range, str::Chars};
 
 pub const CODEPOINT_MASK: u32 = 0x000f_ffff;
@@ -9,16 +10,97 @@ pub const fn is_none(code: u32) -> bool {
   matches!(code, 0..=9 | 14..=31 | 127 | 0xd800..=0xf8ff | 0xe01f0..)
 }
 
-#[cfg(feature = "options")]
 pub const fn is_special_rtl(code: u32) -> bool {
   matches!(code, 0x200e..=0x200f | 0x202a..=0x202e | 0x2066..=0x2069)
 }
 
+// embedding, override and isolate characters, which change the direction of everything that follows them.
+pub const fn is_bidi_override(code: u32) -> bool {
+  matches!(code, 0x202a..=0x202e | 0x2066..=0x2069)
+}
+
+// unicode's Default_Ignorable_Code_Point property, which is not rendered by default.
+pub const fn is_invisible(code: u32) -> bool {
+  matches!(
+    code,
+    0xad
+      | 0x34f
+      | 0x61c
+      | 0x115f..=0x1160
+      | 0x17b4..=0x17b5
+      | 0x180b..=0x180f
+      | 0x200b..=0x200f
+      | 0x202a..=0x202e
+      | 0x2060..=0x206f
+      | 0x3164
+      | 0xfe00..=0xfe0f
+      | 0xfeff
+      | 0xffa0
+      | 0xfff0..=0xfff8
+      | 0x1bca0..=0x1bca3
+      | 0x1d173..=0x1d17a
+      | 0xe0000..=0xe0fff
+  )
+}
+
+// diacritics and other combining characters, excluding invisible ones like zero-width joiners and variation selectors.
+pub const fn is_combining(character: char) -> bool {
+  !is_invisible(character as _) && matches!(Script::of(character), Script::Inherited)
+}
+
 #[cfg(feature = "options")]
 pub const fn is_alphanumeric(code: u32) -> bool {
   matches!(code, 48..=57 | 97..=122 | 65..=90 | 32)
 }
 
+const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+const HIGHS: u64 = u64::from_ne_bytes([0x80; 8]);
+
+// these may report false positives, but never false negatives.
+// see https://graphics.stanford.edu/~seander/bithacks.html#HasLessInWord
+const fn has_less(word: u64, n: u64) -> bool {
+  word.wrapping_sub(ONES * n) & !word & HIGHS != 0
+}
+
+const fn has_more(word: u64, n: u64) -> bool {
+  (word.wrapping_add(ONES * (127 - n)) | word) & HIGHS != 0
+}
+
+const fn has_between(word: u64, m: u64, n: u64) -> bool {
+  let low = word & (ONES * 127);
+
+  (ONES * (127 + n)).wrapping_sub(low) & !word & low.wrapping_add(ONES * (127 - m)) & HIGHS != 0
+}
+
+const fn is_cured_ascii_byte(byte: u8, retain_capitalization: bool) -> bool {
+  matches!(byte, 10..=13 | 32..=126) && (retain_capitalization || !byte.is_ascii_uppercase())
+}
+
+// returns true if every byte is an ASCII character that is already cured, i.e. curing it would return it as is.
+pub fn is_cured_ascii(bytes: &[u8], retain_capitalization: bool) -> bool {
+  let chunks = bytes.chunks_exact(8);
+  let remainder = chunks.remainder();
+
+  for chunk in chunks {
+    let word = u64::from_ne_bytes(chunk.try_into().unwrap());
+
+    // the slow path is only taken for chunks with newlines, uppercase characters or non-ASCII characters.
+    if (has_less(word, 32)
+      || has_more(word, 126)
+      || (!retain_capitalization && has_between(word, 64, 91)))
+      && !chunk
+        .iter()
+        .all(|&byte| is_cured_ascii_byte(byte, retain_capitalization))
+    {
+      return false;
+    }
+  }
+
+  remainder
+    .iter()
+    .all(|&byte| is_cured_ascii_byte(byte, retain_capitalization))
+}
+
 #[derive(Copy, Clone)]
 pub struct Binary {
   bytes: &'static [u8],
@@ -123,13 +205,13 @@ macro_rules! numbered_enum {
   (
     $(#[$enum_meta:meta])*
     $enum_vis:vis enum $enum_name:ident: $enum_type:ty {
-      $($enum_prop:ident = $enum_prop_value:literal),*
+      $($(#[$enum_prop_meta:meta])* $enum_prop:ident = $enum_prop_value:literal),*
     }
   ) => {
     $(#[$enum_meta])*
     #[repr($enum_type)]
     $enum_vis enum $enum_name {
-      $($enum_prop = $enum_prop_value),*
+      $($(#[$enum_prop_meta])* $enum_prop = $enum_prop_value),*
     }
 
     impl $enum_name {
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+// SPDX-FileCopyrightText: 2021-2026 null8626
+
+use super::{Options, similar};
+use std::iter::FusedIterator;
+
+// common leetspeak forms of every lowercase letter that decancer's leetspeak regexes accept and that compare equal to the letter. letters without a regex (c, i and s) have none, and l's only common form ("|_") is compared as "l_".
+#[cfg(feature = "leetspeak")]
+pub const LEETSPEAK: [&[&str]; 26] = [
+  &["/-\\", "/\\"],
+  &["|3", "!3"],
+  &[],
+  &["|)", "[)"],
+  &["[-"],
+  &["|="],
+  &["(_+"],
+  &["|-|", "]-["],
+  &[],
+  &["_|"],
+  &["|<", "|{"],
+  &[],
+  &["|\\/|", "/\\/\\"],
+  &["|\\|", "/\\/"],
+  &["()"],
+  &["|o", "|*"],
+  &["()_"],
+  &["|2", "|?"],
+  &[],
+  &["-|-"],
+  &["|_|"],
+  &["\\/"],
+  &["\\/\\/", "vv"],
+  &["><"],
+  &["`/"],
+  &["7_"],
+];
+
+// how many random combinations in a row may fail to cure back to the target before a randomized iterator gives up.
+const MAX_ATTEMPTS: usize = 10_000;
+
+#[cfg(feature = "separators")]
+const SEPARATORS: [&str; 4] = [" ", ".", "-", "_"];
+
+fn cures_to(input: &str, target: &str, options: Options) -> bool {
+  super::cure(input, options).is_ok_and(|cured| cured == target)
+}
+
+fn candidates(character: char, options: Options) -> Vec<String> {
+  let character = similar::to_lowercase(character);
+  let mut candidates = vec![character.to_string()];
+  let target = candidates[0].clone();
+
+  candidates.extend(similar::similar_chars(character).map(String::from));
+  candidates.extend(character.to_uppercase().map(String::from));
+
+  candidates.extend(
+    super::reverse_cure(&target, options)
+      .flatten()
+      .map(String::from),
+  );
+
+  #[cfg(feature = "leetspeak")]
+  if character.is_ascii_lowercase() {
+    candidates.extend(
+      LEETSPEAK[(character as u8 - b'a') as usize]
+        .iter()
+        .map(|&form| form.to_owned()),
+    );
+  }
+
+  let mut seen = vec![];
+
+  candidates.retain(|candidate| {
+    if seen.contains(candidate) || !cures_to(candidate, &target, options) {
+      false
+    } else {
+      seen.push(candidate.clone());
+      true
+    }
+  });
+
+  candidates
+}
+
+/// An iterator of strings that decancer cures back to a specified string. See [`variants`][variants()] for more information.
+#[must_use]
+pub struct Variants {
+  target: String,
+  options: Options,
+  candidates: Vec<Vec<String>>,
+  separators: Vec<&'static str>,
+  indices: Vec<usize>,
+  separator_index: usize,
+  seed: Option<u64>,
+  attempts: usize,
+  finished: bool,
+}
+
+impl Variants {
+  /// Makes this iterator yield variants in a random order using the specified seed, rather than in a deterministic order. The same seed always yields the same variants.
+  ///
+  /// **NOTE:** This makes this iterator infinite, and a variant can be yielded more than once. The only exception is when too many combinations in a row don't cure back to the specified string, in which case this iterator stops.
+  pub const fn randomized(mut self, seed: u64) -> Self {
+    // xorshift doesn't work with a zero state.
+    self.seed = Some(if seed == 0 {
+      0x9e37_79b9_7f4a_7c15
+    } else {
+      seed
+    });
+
+    self
+  }
+
+  const fn random(&mut self, bound: usize) -> usize {
+    let mut state = self.seed.unwrap();
+
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+
+    self.seed = Some(state);
+
+    #[allow(clippy::cast_possible_truncation)]
+    let output = (state % bound as u64) as usize;
+
+    output
+  }
+
+  fn advance(&mut self) {
+    if self.seed.is_some() {
+      for idx in 0..self.indices.len() {
+        self.indices[idx] = self.random(self.candidates[idx].len());
+      }
+
+      self.separator_index = self.random(self.separators.len());
+
+      return;
+    }
+
+    self.separator_index += 1;
+
+    if self.separator_index < self.separators.len() {
+      return;
+    }
+
+    self.separator_index = 0;
+
+    for (index, candidates) in self.indices.iter_mut().zip(&self.candidates) {
+      *index += 1;
+
+      if *index < candidates.len() {
+        return;
+      }
+
+      *index = 0;
+    }
+
+    self.finished = true;
+  }
+
+  fn current(&self) -> String {
+    let separator = self.separators[self.separator_index];
+    let mut output = String::new();
+
+    for (idx, (&index, candidates)) in self.indices.iter().zip(&self.candidates).enumerate() {
+      if idx > 0 {
+        output.push_str(separator);
+      }
+
+      output.push_str(&candidates[index]);
+    }
+
+    output
+  }
+}
+
+impl Iterator for Variants {
+  type Item = String;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    while !self.finished {
+      self.advance();
+
+      if self.finished {
+        break;
+      }
+
+      // the target itself is not a variant.
+      if self.separator_index == 0 && self.indices.iter().all(|&index| index == 0) {
+        continue;
+      }
+
+      let variant = self.current();
+
+      if cures_to(&variant, &self.target, self.options) {
+        self.attempts = 0;
+
+        return Some(variant);
+      }
+
+      if self.seed.is_some() {
+        self.attempts += 1;
+        self.finished = self.attempts >= MAX_ATTEMPTS;
+      }
+    }
+
+    None
+  }
+}
+
+impl FusedIterator for Variants {}
+
+/// Returns an iterator of strings that decancer cures back to the specified string with the specified [`Options`], which is useful for property tests or for screening reserved names.
+///
+/// The variants mix similar-looking characters, homoglyphs from decancer's translation table, leetspeak forms and separators between characters (if the respective features are enabled). Every yielded variant is verified to be [equal][super::CuredString::eq] to the specified string after being [cured][super::cure()] with the same [`Options`].
+///
+/// By default, every combination is yielded lazily in a deterministic order. This can be changed with [`Variants::randomized`].
+///
+/// ```rust
+/// let options = decancer::Options::default();
+/// let variants: Vec<_> = decancer::variants("admin", options).take(100).collect();
+///
+/// assert_eq!(variants.len(), 100);
+///
+/// for variant in decancer::variants("admin", options).randomized(42).take(100) {
+///   assert_ne!(variant, "admin");
+///   assert_eq!(decancer::cure(&variant, options).unwrap(), "admin");
+/// }
+/// ```
+pub fn variants(target: &str, options: Options) -> Variants {
+  let candidates: Vec<_> = target
+    .chars()
+    .map(|character| candidates(character, options))
+    .collect();
+
+  #[cfg_attr(not(feature = "separators"), allow(unused_mut))]
+  let mut separators = vec![""];
+
+  #[cfg(feature = "separators")]
+  if candidates.len() > 1 {
+    separators.extend(SEPARATORS);
+  }
+
+  // there is nothing to vary if every character can only be itself, and nothing cures back to the target if a character has no candidates at all (e.g: 'é', which is always cured to 'e').
+  let finished = (candidates.iter().all(|candidates| candidates.len() == 1)
+    && separators.len() == 1)
+    || candidates.iter().any(Vec::is_empty);
+
+  Variants {
+    target: target.to_owned(),
+    options,
+    indices: vec![0; candidates.len()],
+    candidates,
+    separators,
+    separator_index: 0,
+    seed: None,
+    attempts: 0,
+    finished,
+  }
+}
@@ -173,6 +173,50 @@ export const BIDI_CLASSES = [
   'PDI'
 ]
 
+export const SCRIPTS = [
+  'Zyyy',
+  'Zinh',
+  'Latn',
+  'Grek',
+  'Copt',
+  'Cyrl',
+  'Glag',
+  'Armn',
+  'Geor',
+  'Hebr',
+  'Arab',
+  'Thaa',
+  'Deva',
+  'Beng',
+  'Guru',
+  'Gujr',
+  'Orya',
+  'Taml',
+  'Telu',
+  'Knda',
+  'Mlym',
+  'Sinh',
+  'Thai',
+  'Laoo',
+  'Tibt',
+  'Mymr',
+  'Khmr',
+  'Mong',
+  'Ethi',
+  'Cher',
+  'Cans',
+  'Runr',
+  'Tfng',
+  'Lisu',
+  'Vaii',
+  'Hani',
+  'Hira',
+  'Kana',
+  'Bopo',
+  'Hang',
+  'Brai'
+]
+
 export const BLACKLISTED_CODEPOINTS = [
   [0, 0x7f],
   [0x200e, 0x200f],
@@ -4,7 +4,8 @@ import {
   BIDI_CLASSES,
   BLACKLISTED_CODEPOINTS,
   CORE_DIR,
-  ROOT_DIR
+  ROOT_DIR,
+  SCRIPTS
 } from './constants.mjs'
 import { containsInclusive, request, strongAssert, SortedSet } from './util.mjs'
 import { writeFile } from 'node:fs/promises'
@@ -48,6 +49,320 @@ function* bidiDerivedIter(bidiDerived) {
   }
 }
 
+function* propertyIter(property) {
+  for (const line of property.split('\n')) {
+    const data = line.split('#')[0].trim()
+
+    if (data.length !== 0) {
+      const [range, value] = data.split(';').map(x => x.trim())
+      const [start, end = start] = range.split('..').map(x => parseInt(x, 16))
+
+      for (let codepoint = start; codepoint <= end; codepoint++) {
+        yield [codepoint, value]
+      }
+    }
+  }
+}
+
+function mergeProperty(expanded) {
+  const dictionary = []
+
+  for (const codepoint of [...expanded.keys()].sort((a, b) => a - b)) {
+    const value = expanded.get(codepoint)
+    const last = dictionary[dictionary.length - 1]
+
+    if (
+      last?.value === value &&
+      last.end === codepoint - 1 &&
+      last.end - last.start < 0xffff
+    ) {
+      last.end = codepoint
+    } else {
+      dictionary.push({
+        start: codepoint,
+        end: codepoint,
+        value
+      })
+    }
+  }
+
+  return dictionary
+}
+
+function propertyDictionaryBuffer(dictionary) {
+  return Buffer.concat(
+    dictionary.map(({ start, end, value }) => {
+      strongAssert(
+        start < 0x100000,
+        'found codepoint above the limit of 0xfffff:',
+        start
+      )
+      strongAssert(
+        value <= 0xfff,
+        'found value above the limit of 0xfff:',
+        value
+      )
+
+      const buf = Buffer.alloc(6)
+
+      buf.writeUint32LE(Number(BigInt(start) | (BigInt(value) << 20n)))
+      buf.writeUint16LE(end - start, 4)
+
+      return buf
+    })
+  )
+}
+
+// layout: the offset of the script extensions, the offset of the script extension sets, every script range, every script extensions range, then every distinct script extension set.
+// scripts in SCRIPTS use their index in it, while other scripts are sorted by their short name after them.
+function scriptsBuffer(scripts, scriptExtensions, aliases) {
+  const shortNames = Object.fromEntries(
+    aliases
+      .split('\n')
+      .map(x => x.split('#')[0].split(';').map(y => y.trim()))
+      .filter(x => x[0] === 'sc')
+      .map(x => [x[2], x[1]])
+  )
+
+  const scriptsExpanded = new Map()
+  const scriptExtensionsExpanded = new Map()
+
+  for (const [codepoint, script] of propertyIter(scripts)) {
+    strongAssert(
+      shortNames[script] !== undefined,
+      'found script without a short name:',
+      script
+    )
+
+    scriptsExpanded.set(codepoint, shortNames[script])
+  }
+
+  for (const [codepoint, extensions] of propertyIter(scriptExtensions)) {
+    scriptExtensionsExpanded.set(codepoint, extensions.split(/\s+/))
+  }
+
+  const others = new Set([
+    ...scriptsExpanded.values(),
+    ...[...scriptExtensionsExpanded.values()].flat()
+  ])
+
+  const indices = [
+    ...SCRIPTS,
+    ...[...others].filter(x => !SCRIPTS.includes(x)).sort()
+  ]
+
+  // the last four indices are reserved for unknown, han with bopomofo, japanese and korean.
+  strongAssert(
+    indices.length <= 252,
+    'found too many scripts:',
+    indices.length
+  )
+
+  for (const [codepoint, script] of scriptsExpanded) {
+    scriptsExpanded.set(codepoint, indices.indexOf(script))
+  }
+
+  const sets = []
+  let setsSize = 0
+
+  for (const [codepoint, extensions] of scriptExtensionsExpanded) {
+    const set = extensions.map(x => indices.indexOf(x)).sort((a, b) => a - b)
+    const key = set.join(' ')
+    let found = sets.find(x => x.key === key)
+
+    if (found === undefined) {
+      found = { key, set, offset: setsSize }
+      setsSize += set.length + 1
+      sets.push(found)
+    }
+
+    scriptExtensionsExpanded.set(codepoint, found.offset)
+  }
+
+  const scriptsDictionary = mergeProperty(scriptsExpanded)
+  const scriptExtensionsDictionary = mergeProperty(scriptExtensionsExpanded)
+
+  console.log(
+    `- stored ${scriptsDictionary.length.toLocaleString('en-US')} script ranges and ${scriptExtensionsDictionary.length.toLocaleString('en-US')} script extension ranges with ${sets.length.toLocaleString('en-US')} distinct sets from ${indices.length.toLocaleString('en-US')} scripts.`
+  )
+
+  const header = Buffer.alloc(4)
+  const scriptExtensionsOffset = 4 + scriptsDictionary.length * 6
+  const setsOffset =
+    scriptExtensionsOffset + scriptExtensionsDictionary.length * 6
+
+  strongAssert(
+    setsOffset <= 0xffff,
+    'scripts buffer length over the limit:',
+    setsOffset
+  )
+
+  header.writeUint16LE(scriptExtensionsOffset)
+  header.writeUint16LE(setsOffset, 2)
+
+  return Buffer.concat([
+    header,
+    propertyDictionaryBuffer(scriptsDictionary),
+    propertyDictionaryBuffer(scriptExtensionsDictionary),
+    ...sets.map(({ set }) => Buffer.from([set.length, ...set]))
+  ])
+}
+
+function stringsPool() {
+  const offsets = new Map()
+  let size = 0
+
+  return {
+    offsets,
+    offset(string) {
+      if (!offsets.has(string)) {
+        offsets.set(string, size)
+        size += Buffer.byteLength(string)
+      }
+
+      return offsets.get(string)
+    }
+  }
+}
+
+// layout: the offset of the decompositions, the offset of the combining classes, the offset of the strings, every confusable, every canonical decomposition, every combining class range, then every distinct string.
+// confusables and decompositions point to a string in NFD, while hangul syllables are left out since they are decomposed algorithmically.
+function confusablesBuffer(confusables, unicode) {
+  const decompositions = new Map()
+  const combiningClasses = new Map()
+
+  for (const data of unicodeIter(unicode)) {
+    const codepoint = parseInt(data[0], 16)
+    const combiningClass = parseInt(data[3])
+
+    if (combiningClass !== 0) {
+      combiningClasses.set(codepoint, combiningClass)
+    }
+
+    // compatibility decompositions start with a <tag>.
+    if (data[5].length !== 0 && !data[5].startsWith('<')) {
+      decompositions.set(
+        codepoint,
+        data[5].split(' ').map(x => parseInt(x, 16))
+      )
+    }
+  }
+
+  const decompose = codepoint =>
+    decompositions.get(codepoint)?.flatMap(decompose) ?? [codepoint]
+
+  const nfd = codepoints => {
+    const output = codepoints.flatMap(decompose)
+
+    for (let i = 1; i < output.length; i++) {
+      const combiningClass = combiningClasses.get(output[i]) ?? 0
+
+      for (
+        let j = i;
+        combiningClass !== 0 &&
+        j > 0 &&
+        (combiningClasses.get(output[j - 1]) ?? 0) > combiningClass;
+        j--
+      ) {
+        ;[output[j - 1], output[j]] = [output[j], output[j - 1]]
+      }
+    }
+
+    return String.fromCodePoint(...output)
+  }
+
+  const strings = stringsPool()
+  const confusablesDictionary = []
+  const decompositionsDictionary = []
+
+  for (const [codepoint, prototype] of propertyIter(confusables)) {
+    // characters that are decomposed never appear after the first NFD pass of a skeleton.
+    if (
+      !decompositions.has(codepoint) &&
+      !containsInclusive(codepoint, 0xac00, 0xd7a3)
+    ) {
+      confusablesDictionary.push({
+        start: codepoint,
+        string: nfd(prototype.split(' ').map(x => parseInt(x, 16)))
+      })
+    }
+  }
+
+  for (const codepoint of decompositions.keys()) {
+    decompositionsDictionary.push({
+      start: codepoint,
+      string: nfd([codepoint])
+    })
+  }
+
+  const dictionaryBuffer = dictionary =>
+    Buffer.concat(
+      dictionary
+        .sort((a, b) => a.start - b.start)
+        .map(({ start, string }) => {
+          const offset = strings.offset(string)
+          const size = Buffer.byteLength(string)
+
+          strongAssert(
+            start < 0x100000,
+            'found codepoint above the limit of 0xfffff:',
+            start
+          )
+          strongAssert(
+            size <= 0xfff,
+            'found string size above the limit of 0xfff:',
+            size
+          )
+          strongAssert(
+            offset <= 0xffff,
+            'found string offset above the limit of 0xffff:',
+            offset
+          )
+
+          const buf = Buffer.alloc(6)
+
+          buf.writeUint32LE(Number(BigInt(start) | (BigInt(size) << 20n)))
+          buf.writeUint16LE(offset, 4)
+
+          return buf
+        })
+    )
+
+  const confusablesRecords = dictionaryBuffer(confusablesDictionary)
+  const decompositionsRecords = dictionaryBuffer(decompositionsDictionary)
+  const combiningClassesRecords = propertyDictionaryBuffer(
+    mergeProperty(combiningClasses)
+  )
+
+  console.log(
+    `- stored ${confusablesDictionary.length.toLocaleString('en-US')} confusables, ${decompositionsDictionary.length.toLocaleString('en-US')} decompositions and ${strings.offsets.size.toLocaleString('en-US')} distinct strings.`
+  )
+
+  const header = Buffer.alloc(6)
+  const decompositionsOffset = 6 + confusablesRecords.length
+  const combiningClassesOffset =
+    decompositionsOffset + decompositionsRecords.length
+  const stringsOffset = combiningClassesOffset + combiningClassesRecords.length
+
+  strongAssert(
+    stringsOffset <= 0xffff,
+    'confusables buffer length over the limit:',
+    stringsOffset
+  )
+
+  header.writeUint16LE(decompositionsOffset)
+  header.writeUint16LE(combiningClassesOffset, 2)
+  header.writeUint16LE(stringsOffset, 4)
+
+  return Buffer.concat([
+    header,
+    confusablesRecords,
+    decompositionsRecords,
+    combiningClassesRecords,
+    ...[...strings.offsets.keys()].map(x => Buffer.from(x))
+  ])
+}
+
 function validCodepoint(codepoint) {
   return codepoint < 0xe01f0 && (codepoint < 0xd800 || codepoint > 0xf8ff)
 }
@@ -320,5 +635,35 @@ void (await Promise.all([
     )
 
     console.log('- wrote to bidi.bin.')
+  })(),
+
+  (async () => {
+    const [scripts, scriptExtensions, aliases] = await Promise.all([
+      request('https://www.unicode.org/Public/UNIDATA/Scripts.txt'),
+      request('https://www.unicode.org/Public/UNIDATA/ScriptExtensions.txt'),
+      request('https://www.unicode.org/Public/UNIDATA/PropertyValueAliases.txt')
+    ])
+
+    const buffer = scriptsBuffer(scripts, scriptExtensions, aliases)
+
+    console.log('- writing to scripts.bin...')
+
+    await writeFile(join(CORE_DIR, 'bin', 'scripts.bin'), buffer)
+
+    console.log('- wrote to scripts.bin.')
+  })(),
+
+  (async () => {
+    const confusables = await request(
+      'https://www.unicode.org/Public/security/latest/confusables.txt'
+    )
+
+    const buffer = confusablesBuffer(confusables, unicode)
+
+    console.log('- writing to confusables.bin...')
+
+    await writeFile(join(CORE_DIR, 'bin', 'confusables.bin'), buffer)
+
+    console.log('- wrote to confusables.bin.')
   })()
 ]))